@@ -0,0 +1,55 @@
+use holdem_shared::*;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+
+/// 서버 REST API 주소
+pub const API_URL: &str = "http://127.0.0.1:7879";
+
+/// 진행 중인 요청. 화면을 막지 않도록 매 프레임 `poll`로 확인한다
+pub struct Pending<T> {
+    rx: Receiver<Result<T, String>>,
+}
+
+impl<T> Pending<T> {
+    /// 끝났으면 결과를 돌려준다.
+    pub fn poll(&self) -> Option<Result<T, String>> {
+        self.rx.try_recv().ok()
+    }
+}
+
+/// 요청 하나를 별도 스레드의 tokio 런타임에서 보낸다.
+fn spawn_get<T>(path: String) -> Pending<T>
+where
+    T: serde::de::DeserializeOwned + Send + 'static,
+{
+    let (tx, rx) = channel();
+    thread::spawn(move || {
+        let result = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| e.to_string())
+            .and_then(|runtime| runtime.block_on(get_json(&path)));
+        let _ = tx.send(result);
+    });
+    Pending { rx }
+}
+
+async fn get_json<T: serde::de::DeserializeOwned>(path: &str) -> Result<T, String> {
+    let response = reqwest::get(format!("{}{}", API_URL, path))
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    response.json::<T>().await.map_err(|e| e.to_string())
+}
+
+/// 로비 테이블 목록 (`GET /tables`)
+pub fn fetch_tables() -> Pending<Vec<TableSummary>> {
+    spawn_get("/tables".to_string())
+}
+
+/// 플레이어 칩과 기록 (`GET /players/{id}`)
+pub fn fetch_player(player_id: &str) -> Pending<PlayerInfo> {
+    spawn_get(format!("/players/{}", player_id))
+}
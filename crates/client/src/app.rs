@@ -1,8 +1,14 @@
+use crate::api::{self, Pending};
 use crate::network::NetworkClient;
 use crate::ui;
 use eframe::egui;
 use holdem_shared::*;
 
+/// 줄 단위 JSON TCP 서버 주소
+pub const SERVER_ADDR: &str = "127.0.0.1:7878";
+/// WebSocket 서버 주소
+pub const SERVER_WS_URL: &str = "ws://127.0.0.1:7879/ws";
+
 pub enum ConnectionState {
     Disconnected,
     Connected,
@@ -17,6 +23,30 @@ pub struct PokerClient {
     pub network: Option<NetworkClient>,
     pub status_message: String,
     pub raise_amount: i32,
+    /// TCP 대신 WebSocket으로 연결
+    pub use_websocket: bool,
+    /// 로비에서 받아 온 테이블 목록
+    pub tables: Vec<TableSummary>,
+    /// 앉을 테이블 (`None`이면 서버 기본 테이블)
+    pub selected_table: Option<TableId>,
+    pub tables_request: Option<Pending<Vec<TableSummary>>>,
+    /// 재접속 토큰 (연결이 끊기면 `Resume`으로 자리에 돌아간다)
+    pub session_token: Option<String>,
+    /// 내 칩과 핸드 기록
+    pub player_info: Option<PlayerInfo>,
+    pub player_request: Option<Pending<PlayerInfo>>,
+    /// 서버가 핸드 전에 공개한 셔플 커밋 (마지막 것이 다음 핸드)
+    pub shuffle_commitments: Vec<String>,
+    /// 다음 셔플에 보탤 엔트로피 입력
+    pub entropy_input: String,
+    /// 보낸 엔트로피와 그 핸드의 커밋. 공개된 셔플에 들어 있는지 확인한다
+    pub sent_entropy: Option<(String, String)>,
+    /// 관리자 토큰 입력. 남이 만든 테이블의 설정을 바꿀 때 함께 보낸다
+    pub admin_token: String,
+    /// 직전 핸드의 쇼다운 결과
+    pub showdown: Vec<ShowdownHand>,
+    /// 승자의 핸드에서 승부를 가른 카드 (강조 표시)
+    pub winning_cards: Vec<Card>,
 }
 
 impl Default for PokerClient {
@@ -30,13 +60,37 @@ impl Default for PokerClient {
             network: None,
             status_message: String::new(),
             raise_amount: 20,
+            use_websocket: false,
+            tables: Vec::new(),
+            selected_table: None,
+            tables_request: None,
+            session_token: None,
+            player_info: None,
+            player_request: None,
+            shuffle_commitments: Vec::new(),
+            entropy_input: String::new(),
+            sent_entropy: None,
+            admin_token: String::new(),
+            showdown: Vec::new(),
+            winning_cards: Vec::new(),
         }
     }
 }
 
 impl PokerClient {
+    /// 입력한 관리자 토큰. 비어 있으면 `None`
+    pub fn admin_token(&self) -> Option<String> {
+        let token = self.admin_token.trim();
+        (!token.is_empty()).then(|| token.to_string())
+    }
+
     pub fn connect(&mut self) {
-        match NetworkClient::connect("127.0.0.1:7878") {
+        let result = if self.use_websocket {
+            NetworkClient::connect_ws(SERVER_WS_URL)
+        } else {
+            NetworkClient::connect(SERVER_ADDR).map_err(|e| e.to_string())
+        };
+        match result {
             Ok(network) => {
                 println!("✅ 서버 연결 성공");
                 self.connection_state = ConnectionState::Connected;
@@ -50,6 +104,68 @@ impl PokerClient {
         }
     }
 
+    /// 다시 연결해서 끊기기 전 자리로 돌아간다.
+    pub fn resume(&mut self) {
+        let Some(token) = self.session_token.clone() else {
+            return;
+        };
+        self.connect();
+        if self.network.is_some() {
+            self.send_message(ClientMessage::Resume { token });
+        }
+    }
+
+    /// 로비 테이블 목록을 다시 받아 온다.
+    pub fn refresh_tables(&mut self) {
+        self.tables_request = Some(api::fetch_tables());
+    }
+
+    /// 내 칩과 기록을 다시 받아 온다.
+    pub fn refresh_player_info(&mut self) {
+        if let Some(player_id) = &self.player_id {
+            self.player_request = Some(api::fetch_player(player_id));
+        }
+    }
+
+    /// 끝난 REST 요청 결과를 반영한다.
+    fn poll_requests(&mut self) {
+        if let Some(result) = self.tables_request.as_ref().and_then(|r| r.poll()) {
+            self.tables_request = None;
+            match result {
+                Ok(tables) => self.tables = tables,
+                Err(e) => self.status_message = format!("테이블 목록을 받지 못했습니다: {}", e),
+            }
+        }
+        if let Some(result) = self.player_request.as_ref().and_then(|r| r.poll()) {
+            self.player_request = None;
+            match result {
+                Ok(info) => self.player_info = Some(info),
+                Err(e) => self.status_message = format!("기록을 받지 못했습니다: {}", e),
+            }
+        }
+    }
+
+    /// 입력한 엔트로피를 다음 핸드 셔플에 보탠다.
+    pub fn add_entropy(&mut self) {
+        let entropy = self.entropy_input.trim().to_string();
+        let Some(commitment) = self.shuffle_commitments.last().cloned() else {
+            return;
+        };
+        if entropy.is_empty() {
+            return;
+        }
+        if entropy.len() > MAX_ENTROPY_LEN {
+            self.status_message = format!("❌ 엔트로피는 {}바이트 이하여야 합니다", MAX_ENTROPY_LEN);
+            return;
+        }
+        self.send_message(ClientMessage::AddEntropy {
+            entropy: entropy.clone(),
+        });
+        self.entropy_input.clear();
+        self.status_message = "🎲 다음 핸드 셔플에 엔트로피를 보탰습니다".to_string();
+        self.sent_entropy = Some((commitment, entropy));
+    }
+
     pub fn send_message(&mut self, msg: ClientMessage) {
         if let Some(network) = &mut self.network {
             network.send(msg);
@@ -60,28 +176,124 @@ impl PokerClient {
         if let Some(network) = &mut self.network {
             while let Some(msg) = network.receive() {
                 match msg {
-                    ServerMessage::Welcome { player_id, chips } => {
+                    ServerMessage::Welcome { player_id, chips, token } => {
                         self.player_id = Some(player_id);
+                        self.session_token = Some(token);
                         self.status_message = format!("환영합니다! 칩: ${}", chips);
                     }
+                    ServerMessage::TableCreated { table_id, config } => {
+                        self.selected_table = Some(table_id);
+                        self.tables_request = Some(api::fetch_tables());
+                        self.status_message =
+                            format!("테이블 {} 생성: {} {}", table_id, config.variant, config.stakes());
+                    }
+                    ServerMessage::TableInfo { table_id, config } => {
+                        self.status_message = format!(
+                            "테이블 {}: {} {} · 바이인 ${}~${}",
+                            table_id,
+                            config.variant,
+                            config.stakes(),
+                            config.min_buy_in,
+                            config.max_buy_in
+                        );
+                    }
                     ServerMessage::GameState(state) => {
                         self.game_state = Some(state);
                     }
                     ServerMessage::DealCards { cards } => {
                         self.my_cards = cards;
+                        self.showdown.clear();
+                        self.winning_cards.clear();
                         self.status_message = "카드를 받았습니다!".to_string();
                     }
                     ServerMessage::PlayerAction { player_id, action } => {
                         self.status_message = format!("{}가 {}", player_id, action);
                     }
-                    ServerMessage::GameOver { winner_id, amount } => {
-                        self.status_message = format!("🎉 {}가 ${} 획득!", winner_id, amount);
+                    ServerMessage::GameOver { winners, showdown } => {
+                        let results: Vec<String> = winners
+                            .iter()
+                            .map(|w| {
+                                match showdown.iter().find(|h| h.player_id == w.player_id) {
+                                    Some(ShowdownHand { value, low: Some(low), .. }) => {
+                                        format!("{}가 ${} ({} / {})", w.player_id, w.amount, value, low)
+                                    }
+                                    Some(hand) => {
+                                        format!("{}가 ${} ({})", w.player_id, w.amount, hand.value)
+                                    }
+                                    None => format!("{}가 ${}", w.player_id, w.amount),
+                                }
+                            })
+                            .collect();
+                        self.status_message = format!("🎉 {} 획득!", results.join(", "));
+
+                        self.winning_cards = showdown
+                            .iter()
+                            .filter(|h| winners.iter().any(|w| w.player_id == h.player_id))
+                            .flat_map(|h| h.deciding.iter().copied())
+                            .collect();
+                        self.showdown = showdown;
+                    }
+                    ServerMessage::ShuffleCommitment { commitment } => {
+                        self.shuffle_commitments.push(commitment);
+                    }
+                    ServerMessage::ShuffleReveal {
+                        variant,
+                        commitment,
+                        server_seed,
+                        client_entropy,
+                        deal_log,
+                    } => {
+                        let committed = self.shuffle_commitments.contains(&commitment);
+                        let verified =
+                            verify_hand(variant, &commitment, &server_seed, &client_entropy, &deal_log);
+                        // 내가 받은 카드가 기록과 같은지도 확인
+                        let my_cards: Vec<Card> = deal_log
+                            .iter()
+                            .filter(|d| {
+                                matches!(&d.dealt.target, DealTarget::Player(id) if Some(id) == self.player_id.as_ref())
+                            })
+                            .map(|d| d.dealt.card)
+                            .collect();
+                        // 기록에 내 카드가 하나도 없으면 확인한 것이 없으니 실패로 본다
+                        let mine_found = !my_cards.is_empty();
+                        let mine_match = mine_found && my_cards.iter().all(|c| self.my_cards.contains(c));
+                        // 이 핸드에 엔트로피를 보탰으면 공개된 셔플에 들어 있어야 한다
+                        let entropy_used = match &self.sent_entropy {
+                            Some((sent_for, entropy)) if sent_for == &commitment => {
+                                client_entropy.contains(entropy)
+                            }
+                            _ => true,
+                        };
+
+                        let result = match verified {
+                            Ok(_) if committed && mine_match && entropy_used => "🔒 셔플 검증 완료".to_string(),
+                            Ok(_) if !entropy_used => "⚠️ 셔플 검증 실패: 내 엔트로피가 빠짐".to_string(),
+                            Ok(_) if !mine_found => "⚠️ 셔플 검증 실패: 기록에 내 카드가 없음".to_string(),
+                            Ok(_) => "⚠️ 셔플 검증 실패: 커밋 또는 카드 불일치".to_string(),
+                            Err(e) => format!("⚠️ 셔플 검증 실패: {}", e),
+                        };
+                        // 직전 게임 결과 메시지 뒤에 붙인다
+                        self.status_message = format!("{} ({})", self.status_message, result);
+                        self.shuffle_commitments.retain(|c| c != &commitment);
+                        if self.sent_entropy.as_ref().is_some_and(|(sent_for, _)| sent_for == &commitment) {
+                            self.sent_entropy = None;
+                        }
+                    }
+                    ServerMessage::ActionRejected { error } => {
+                        self.status_message = format!("❌ {}", error);
                     }
                     ServerMessage::Error { message } => {
                         self.status_message = format!("❌ {}", message);
                     }
                 }
             }
+            // 남은 메시지를 다 읽은 뒤 끊긴 연결을 정리한다. 토큰은 이어하기용으로 남긴다
+            if !network.is_connected() {
+                self.network = None;
+                self.player_id = None;
+                self.connection_state = ConnectionState::Disconnected;
+                self.status_message = "서버와 연결이 끊겼습니다".to_string();
+            }
         }
     }
 }
@@ -89,6 +301,7 @@ impl PokerClient {
 impl eframe::App for PokerClient {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.handle_server_messages();
+        self.poll_requests();
         ctx.request_repaint();
 
         egui::CentralPanel::default().show(ctx, |ui| {
@@ -1,25 +1,33 @@
+use futures_util::{SinkExt, StreamExt};
 use holdem_shared::*;
 use std::io::{BufRead, BufReader, Write};
 use std::net::TcpStream;
-use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Arc;
 use std::thread;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio_tungstenite::tungstenite::Message;
 
 pub struct NetworkClient {
-    tx: Sender<ClientMessage>,
+    tx: UnboundedSender<ClientMessage>,
     rx: Receiver<ServerMessage>,
+    /// 수신이 끝나면 (서버가 연결을 닫으면) false
+    connected: Arc<AtomicBool>,
 }
 
 impl NetworkClient {
+    /// 줄 단위 JSON TCP 연결
     pub fn connect(addr: &str) -> Result<Self, std::io::Error> {
         let stream = TcpStream::connect(addr)?;
-        
-        let (tx_to_server, rx_from_ui) = channel::<ClientMessage>();
+
+        let (tx_to_server, mut rx_from_ui) = unbounded_channel::<ClientMessage>();
         let (tx_to_ui, rx_to_ui) = channel::<ServerMessage>();
 
         // 서버로 메시지 전송 스레드
         let mut write_stream = stream.try_clone()?;
         thread::spawn(move || {
-            while let Ok(msg) = rx_from_ui.recv() {
+            while let Some(msg) = rx_from_ui.blocking_recv() {
                 let json = serde_json::to_string(&msg).unwrap() + "\n";
                 if write_stream.write_all(json.as_bytes()).is_err() {
                     break;
@@ -29,6 +37,8 @@ impl NetworkClient {
         });
 
         // 서버로부터 메시지 수신 스레드
+        let connected = Arc::new(AtomicBool::new(true));
+        let reader_connected = connected.clone();
         thread::spawn(move || {
             let mut reader = BufReader::new(stream);
             loop {
@@ -43,14 +53,92 @@ impl NetworkClient {
                     Err(_) => break,
                 }
             }
+            reader_connected.store(false, Ordering::Relaxed);
         });
 
         Ok(Self {
             tx: tx_to_server,
             rx: rx_to_ui,
+            connected,
         })
     }
 
+    /// WebSocket 연결 (예: `ws://127.0.0.1:7879/ws`). 텍스트 프레임 하나에 메시지 하나
+    pub fn connect_ws(url: &str) -> Result<Self, String> {
+        let url = url.to_string();
+        let (tx_to_server, mut rx_from_ui) = unbounded_channel::<ClientMessage>();
+        let (tx_to_ui, rx_to_ui) = channel::<ServerMessage>();
+        let (connected_tx, connected_rx) = channel::<Result<(), String>>();
+        let connected = Arc::new(AtomicBool::new(true));
+        let socket_connected = connected.clone();
+
+        // 송수신은 전용 스레드의 tokio 런타임에서 처리한다
+        thread::spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    let _ = connected_tx.send(Err(e.to_string()));
+                    return;
+                }
+            };
+
+            runtime.block_on(async move {
+                let mut socket = match tokio_tungstenite::connect_async(url.as_str()).await {
+                    Ok((socket, _)) => {
+                        let _ = connected_tx.send(Ok(()));
+                        socket
+                    }
+                    Err(e) => {
+                        let _ = connected_tx.send(Err(e.to_string()));
+                        return;
+                    }
+                };
+
+                loop {
+                    tokio::select! {
+                        msg = rx_from_ui.recv() => {
+                            let Some(msg) = msg else {
+                                let _ = socket.close(None).await;
+                                break;
+                            };
+                            let json = serde_json::to_string(&msg).unwrap();
+                            if socket.send(Message::text(json)).await.is_err() {
+                                break;
+                            }
+                        }
+                        frame = socket.next() => {
+                            match frame {
+                                Some(Ok(Message::Text(text))) => {
+                                    if let Ok(msg) = serde_json::from_str::<ServerMessage>(&text) {
+                                        let _ = tx_to_ui.send(msg);
+                                    }
+                                }
+                                Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                                Some(Ok(_)) => {}
+                            }
+                        }
+                    }
+                }
+                socket_connected.store(false, Ordering::Relaxed);
+            });
+        });
+
+        connected_rx
+            .recv()
+            .map_err(|e| e.to_string())??;
+
+        Ok(Self {
+            tx: tx_to_server,
+            rx: rx_to_ui,
+            connected,
+        })
+    }
+
+    /// 서버와 아직 연결되어 있는지. 받지 않은 메시지가 남아 있을 수 있다
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
     pub fn send(&mut self, msg: ClientMessage) {
         let _ = self.tx.send(msg);
     }
@@ -58,4 +146,4 @@ impl NetworkClient {
     pub fn receive(&mut self) -> Option<ServerMessage> {
         self.rx.try_recv().ok()
     }
-}
\ No newline at end of file
+}
@@ -1,16 +1,22 @@
 use eframe::egui;
 use holdem_shared::*;
 
-/// 카드 렌더링 컴포넌트
-pub fn render_card(ui: &mut egui::Ui, card: &Card) {
+/// 카드 렌더링 컴포넌트. 쇼다운에서 승부를 가른 카드는 테두리를 강조한다
+pub fn render_card(ui: &mut egui::Ui, card: &Card, highlighted: bool) {
     let color = match card.suit {
         Suit::Hearts | Suit::Diamonds => egui::Color32::from_rgb(220, 50, 50),
         Suit::Clubs | Suit::Spades => egui::Color32::from_rgb(50, 50, 50),
     };
 
+    let stroke = if highlighted {
+        egui::Stroke::new(4.0, egui::Color32::GOLD)
+    } else {
+        egui::Stroke::new(2.0, egui::Color32::GRAY)
+    };
+
     egui::Frame::new()
         .fill(egui::Color32::WHITE)
-        .stroke(egui::Stroke::new(2.0, egui::Color32::GRAY))
+        .stroke(stroke)
         .inner_margin(8.0)
         .corner_radius(5.0)
         .show(ui, |ui| {
@@ -31,7 +37,12 @@ pub fn render_card(ui: &mut egui::Ui, card: &Card) {
 }
 
 /// 플레이어 정보 카드 렌더링
-pub fn render_player_card(ui: &mut egui::Ui, player: &Player, is_me: bool) {
+pub fn render_player_card(
+    ui: &mut egui::Ui,
+    player: &Player,
+    is_me: bool,
+    showdown: Option<&ShowdownHand>,
+) {
     let frame = if is_me {
         egui::Frame::new()
             .fill(egui::Color32::from_rgb(100, 150, 255))
@@ -67,6 +78,39 @@ pub fn render_player_card(ui: &mut egui::Ui, player: &Player, is_me: bool) {
                     egui::RichText::new("❌ 폴드")
                         .color(egui::Color32::WHITE),
                 );
+            } else if player.all_in {
+                ui.label(
+                    egui::RichText::new("🔥 올인")
+                        .color(egui::Color32::WHITE),
+                );
+            }
+            if !player.is_active {
+                ui.label(
+                    egui::RichText::new("📴 연결 끊김")
+                        .color(egui::Color32::WHITE),
+                );
+            }
+            // 쇼다운에서 공개된 상대 카드
+            if !is_me && !player.hand.is_empty() {
+                let cards: Vec<String> = player.hand.iter().map(|c| c.to_string()).collect();
+                ui.label(
+                    egui::RichText::new(format!("🃏 {}", cards.join(" ")))
+                        .color(egui::Color32::WHITE),
+                );
+            }
+            if let Some(hand) = showdown {
+                ui.label(
+                    egui::RichText::new(hand.value.to_string())
+                        .color(egui::Color32::WHITE)
+                        .italics(),
+                );
+                if let Some(low) = &hand.low {
+                    ui.label(
+                        egui::RichText::new(low.to_string())
+                            .color(egui::Color32::WHITE)
+                            .italics(),
+                    );
+                }
             }
         });
     });
@@ -90,6 +134,11 @@ pub fn render_game_info(ui: &mut egui::Ui, state: &GameState) {
             egui::RichText::new(format!("🎲 {:?}", state.phase))
                 .size(18.0),
         );
+        ui.separator();
+        ui.label(
+            egui::RichText::new(format!("🃏 {} · {}", state.config.variant, state.config.stakes()))
+                .size(18.0),
+        );
     });
 }
 
@@ -23,13 +23,13 @@ pub fn render(app: &mut PokerClient, ui: &mut egui::Ui) {
 
             // 커뮤니티 카드
             if !state.community_cards.is_empty() {
-                render_community_cards(ui, &state);
+                render_community_cards(ui, &state, &app.winning_cards);
                 ui.add_space(20.0);
             }
 
             // 내 카드
             if !app.my_cards.is_empty() {
-                render_my_cards(ui, &app.my_cards);
+                render_my_cards(ui, &app.my_cards, &app.winning_cards);
                 ui.add_space(20.0);
             }
 
@@ -52,13 +52,14 @@ fn render_players(app: &PokerClient, ui: &mut egui::Ui, state: &GameState) {
     ui.horizontal(|ui| {
         for player in &state.players {
             let is_me = Some(&player.id) == app.player_id.as_ref();
-            components::render_player_card(ui, player, is_me);
+            let showdown = app.showdown.iter().find(|h| h.player_id == player.id);
+            components::render_player_card(ui, player, is_me, showdown);
             ui.add_space(10.0);
         }
     });
 }
 
-fn render_community_cards(ui: &mut egui::Ui, state: &GameState) {
+fn render_community_cards(ui: &mut egui::Ui, state: &GameState, highlighted: &[Card]) {
     ui.label(
         egui::RichText::new("커뮤니티 카드")
             .size(18.0)
@@ -68,13 +69,13 @@ fn render_community_cards(ui: &mut egui::Ui, state: &GameState) {
     
     ui.horizontal(|ui| {
         for card in &state.community_cards {
-            components::render_card(ui, card);
+            components::render_card(ui, card, highlighted.contains(card));
             ui.add_space(5.0);
         }
     });
 }
 
-fn render_my_cards(ui: &mut egui::Ui, cards: &[Card]) {
+fn render_my_cards(ui: &mut egui::Ui, cards: &[Card], highlighted: &[Card]) {
     ui.label(
         egui::RichText::new("내 카드")
             .size(18.0)
@@ -84,7 +85,7 @@ fn render_my_cards(ui: &mut egui::Ui, cards: &[Card]) {
     
     ui.horizontal(|ui| {
         for card in cards {
-            components::render_card(ui, card);
+            components::render_card(ui, card, highlighted.contains(card));
             ui.add_space(5.0);
         }
     });
@@ -93,6 +94,13 @@ fn render_my_cards(ui: &mut egui::Ui, cards: &[Card]) {
 fn render_actions(app: &mut PokerClient, ui: &mut egui::Ui, state: &GameState) {
     match state.phase {
         GamePhase::Waiting => {
+            render_variant_picker(app, ui, state);
+            ui.add_space(10.0);
+            render_entropy_input(app, ui);
+            ui.add_space(10.0);
+            render_player_info(app, ui);
+            ui.add_space(10.0);
+
             if ui.button(
                 egui::RichText::new("🎮 게임 시작 (Ready)")
                     .size(18.0)
@@ -113,6 +121,60 @@ fn render_actions(app: &mut PokerClient, ui: &mut egui::Ui, state: &GameState) {
     }
 }
 
+/// 다음 핸드 셔플에 내 엔트로피를 보탠다 (서버 혼자 덱을 정하지 못하게)
+fn render_entropy_input(app: &mut PokerClient, ui: &mut egui::Ui) {
+    ui.horizontal(|ui| {
+        ui.label("🎲 엔트로피:");
+        ui.text_edit_singleline(&mut app.entropy_input);
+        if ui.button("셔플에 보태기").clicked() {
+            app.add_entropy();
+        }
+    });
+}
+
+/// REST API로 받아 온 내 칩과 최근 핸드 기록
+fn render_player_info(app: &mut PokerClient, ui: &mut egui::Ui) {
+    if ui.button("📈 내 기록").clicked() && app.player_request.is_none() {
+        app.refresh_player_info();
+    }
+    let Some(info) = &app.player_info else {
+        return;
+    };
+
+    ui.label(format!("💰 ${} · {}핸드", info.chips, info.history.len()));
+    for hand in info.history.iter().rev().take(5) {
+        let color = if hand.net >= 0 {
+            egui::Color32::DARK_GREEN
+        } else {
+            egui::Color32::RED
+        };
+        ui.label(
+            egui::RichText::new(format!("테이블 {} #{}: {:+} (${})", hand.table_id, hand.hand, hand.net, hand.chips))
+                .color(color),
+        );
+    }
+}
+
+/// 핸드 사이에 테이블 게임 종류를 고른다. 다른 종류를 클릭한 프레임에만 한 번 보낸다
+fn render_variant_picker(app: &mut PokerClient, ui: &mut egui::Ui, state: &GameState) {
+    let mut selected = state.config.variant;
+    let mut changed = false;
+    egui::ComboBox::from_label("게임 종류")
+        .selected_text(selected.name())
+        .show_ui(ui, |ui| {
+            for variant in Variant::ALL {
+                changed |= ui.selectable_value(&mut selected, variant, variant.name()).changed();
+            }
+        });
+
+    if changed && selected != state.config.variant {
+        app.send_message(ClientMessage::SetVariant {
+            variant: selected,
+            admin_token: app.admin_token(),
+        });
+    }
+}
+
 fn render_betting_actions(app: &mut PokerClient, ui: &mut egui::Ui, state: &GameState) {
     let my_player = state
         .players
@@ -166,9 +228,11 @@ fn render_betting_actions(app: &mut PokerClient, ui: &mut egui::Ui, state: &Game
 
                 ui.add_space(10.0);
 
-                // 레이즈 버튼
+                // 레이즈 버튼 (최소 레이즈 ~ 올인)
+                let max_raise = (player.chips + player.bet - state.current_bet).max(1);
+                let min_raise = state.min_raise.clamp(1, max_raise);
                 ui.add(
-                    egui::Slider::new(&mut app.raise_amount, 10..=100)
+                    egui::Slider::new(&mut app.raise_amount, min_raise..=max_raise)
                         .text("$")
                 );
                 
@@ -1,6 +1,6 @@
-use crate::app::PokerClient;
+use crate::app::{PokerClient, SERVER_ADDR, SERVER_WS_URL};
 use eframe::egui;
-use holdem_shared::ClientMessage;
+use holdem_shared::{ClientMessage, TableConfig};
 
 /// 연결 전 로비 화면
 pub fn render(app: &mut PokerClient, ui: &mut egui::Ui) {
@@ -14,7 +14,9 @@ pub fn render(app: &mut PokerClient, ui: &mut egui::Ui) {
         
         ui.add_space(30.0);
         
-        ui.label("서버 주소: 127.0.0.1:7878");
+        let address = if app.use_websocket { SERVER_WS_URL } else { SERVER_ADDR };
+        ui.label(format!("서버 주소: {}", address));
+        ui.checkbox(&mut app.use_websocket, "WebSocket으로 연결");
         
         ui.add_space(20.0);
         
@@ -28,6 +30,13 @@ pub fn render(app: &mut PokerClient, ui: &mut egui::Ui) {
         if ui.button("🔌 서버 연결").clicked() && !app.player_name.is_empty() {
             app.connect();
         }
+        if app.session_token.is_some() && ui.button("🔁 이어하기").clicked() {
+            app.resume();
+        }
+        
+        ui.add_space(20.0);
+        
+        render_tables(app, ui);
         
         ui.add_space(20.0);
         
@@ -54,18 +63,77 @@ pub fn render_join(app: &mut PokerClient, ui: &mut egui::Ui) {
             ui.text_edit_singleline(&mut app.player_name);
         });
         
+        ui.horizontal(|ui| {
+            ui.label("관리자 토큰 (선택):");
+            ui.add(egui::TextEdit::singleline(&mut app.admin_token).password(true));
+        });
+        
         ui.add_space(10.0);
         
-        if ui.button("🎮 게임 참가").clicked() && !app.player_name.is_empty() {
-            app.send_message(ClientMessage::Join {
-                name: app.player_name.clone(),
-            });
-        }
+        ui.horizontal(|ui| {
+            let target = match app.selected_table {
+                Some(id) => format!("🎮 테이블 {} 참가", id),
+                None => "🎮 게임 참가".to_string(),
+            };
+            if ui.button(target).clicked() && !app.player_name.is_empty() {
+                app.send_message(ClientMessage::Join {
+                    table_id: app.selected_table,
+                    name: app.player_name.clone(),
+                    buy_in: None,
+                });
+            }
+            if ui.button("➕ 새 테이블").clicked() {
+                app.send_message(ClientMessage::CreateTable {
+                    config: TableConfig::default(),
+                });
+            }
+        });
         
         ui.add_space(20.0);
         
         if !app.status_message.is_empty() {
             ui.label(&app.status_message);
         }
+        
+        ui.add_space(20.0);
+        
+        render_tables(app, ui);
+    });
+}
+
+/// REST API로 받아 온 테이블 목록
+fn render_tables(app: &mut PokerClient, ui: &mut egui::Ui) {
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("테이블 목록").size(18.0).strong());
+        let label = if app.tables_request.is_some() { "⏳" } else { "🔄 새로고침" };
+        if ui.button(label).clicked() && app.tables_request.is_none() {
+            app.refresh_tables();
+        }
     });
-}
\ No newline at end of file
+    
+    egui::Grid::new("tables").striped(true).show(ui, |ui| {
+        ui.label("#");
+        ui.label("게임");
+        ui.label("블라인드");
+        ui.label("좌석");
+        ui.label("단계");
+        ui.end_row();
+        
+        for table in &app.tables {
+            let selected = app.selected_table == Some(table.id);
+            if ui.selectable_label(selected, table.id.to_string()).clicked() {
+                app.selected_table = if selected { None } else { Some(table.id) };
+            }
+            ui.label(table.config.variant.name());
+            ui.label(table.config.stakes());
+            let seats = format!("{}/{}", table.seated, table.config.seats);
+            if table.is_full() {
+                ui.label(egui::RichText::new(seats).color(egui::Color32::RED));
+            } else {
+                ui.label(seats);
+            }
+            ui.label(format!("{:?}", table.phase));
+            ui.end_row();
+        }
+    });
+}
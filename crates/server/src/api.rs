@@ -0,0 +1,150 @@
+use crate::game::TableCommand;
+use crate::lobby::{Lobby, LobbyError};
+use crate::ws;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use holdem_shared::*;
+use serde::Serialize;
+use std::sync::Arc;
+use tower_http::cors::CorsLayer;
+
+/// 핸들러가 함께 쓰는 상태
+#[derive(Clone)]
+pub struct AppState {
+    /// 테이블 생성/삭제에 필요한 관리자 토큰도 로비가 들고 있다
+    pub lobby: Arc<Lobby>,
+}
+
+/// HTTP 서버 라우터. 게임 WebSocket(`/ws`)도 같은 포트에서 받는다.
+///
+/// - `GET /tables` 테이블 목록
+/// - `GET /tables/{id}` 테이블 공개 상태
+/// - `POST /tables` 테이블 생성 (관리자, 본문은 `TableConfig`)
+/// - `DELETE /tables/{id}` 테이블 닫기 (관리자)
+/// - `GET /players/{id}` 앉아 있는 플레이어의 칩과 핸드 기록
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/tables", get(list_tables).post(create_table))
+        .route("/tables/{id}", get(table_detail).delete(close_table))
+        .route("/players/{id}", get(player_info))
+        .route("/ws", get(ws::upgrade))
+        .layer(CorsLayer::permissive())
+        .with_state(state)
+}
+
+/// API 에러. 본문은 `{ "error": "..." }`
+#[derive(Debug)]
+pub enum ApiError {
+    TableNotFound,
+    PlayerNotFound,
+    /// 관리자 토큰이 없거나 틀림
+    Unauthorized,
+    /// 서버에 관리자 토큰이 설정되지 않음
+    AdminDisabled,
+    InvalidConfig(ConfigError),
+    /// 열 수 있는 테이블 수를 넘음
+    TooManyTables { max: usize },
+}
+
+impl From<LobbyError> for ApiError {
+    fn from(error: LobbyError) -> Self {
+        match error {
+            LobbyError::InvalidConfig(error) => ApiError::InvalidConfig(error),
+            LobbyError::TooManyTables { max } => ApiError::TooManyTables { max },
+            // REST로 만든 테이블은 주인이 없다
+            LobbyError::TooManyOwnTables { max } => ApiError::TooManyTables { max },
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ApiError::TableNotFound => (StatusCode::NOT_FOUND, "테이블이 없습니다".to_string()),
+            ApiError::PlayerNotFound => (StatusCode::NOT_FOUND, "플레이어가 없습니다".to_string()),
+            ApiError::Unauthorized => (StatusCode::UNAUTHORIZED, "관리자 토큰이 필요합니다".to_string()),
+            ApiError::AdminDisabled => {
+                (StatusCode::FORBIDDEN, "관리자 API가 꺼져 있습니다".to_string())
+            }
+            ApiError::InvalidConfig(error) => (StatusCode::BAD_REQUEST, error.to_string()),
+            ApiError::TooManyTables { max } => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                LobbyError::TooManyTables { max }.to_string(),
+            ),
+        };
+        (status, Json(ErrorBody { error: message })).into_response()
+    }
+}
+
+/// `Authorization: Bearer <토큰>` 확인
+fn require_admin(state: &AppState, headers: &HeaderMap) -> Result<(), ApiError> {
+    if !state.lobby.admin_enabled() {
+        return Err(ApiError::AdminDisabled);
+    }
+    let given = headers
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if state.lobby.is_admin(given) {
+        Ok(())
+    } else {
+        Err(ApiError::Unauthorized)
+    }
+}
+
+async fn list_tables(State(state): State<AppState>) -> Json<Vec<TableSummary>> {
+    let mut tables = Vec::new();
+    for handle in state.lobby.tables() {
+        // 그 사이에 닫힌 테이블은 건너뛴다
+        if let Some(summary) = handle.summary().await {
+            tables.push(summary);
+        }
+    }
+    Json(tables)
+}
+
+async fn table_detail(
+    State(state): State<AppState>,
+    Path(id): Path<TableId>,
+) -> Result<Json<TableDetail>, ApiError> {
+    let handle = state.lobby.table(id).ok_or(ApiError::TableNotFound)?;
+    handle.detail().await.map(Json).ok_or(ApiError::TableNotFound)
+}
+
+async fn create_table(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(config): Json<TableConfig>,
+) -> Result<(StatusCode, Json<TableSummary>), ApiError> {
+    require_admin(&state, &headers)?;
+    let id = state.lobby.create_table(config, None)?;
+    let handle = state.lobby.table(id).ok_or(ApiError::TableNotFound)?;
+    let summary = handle.summary().await.ok_or(ApiError::TableNotFound)?;
+    Ok((StatusCode::CREATED, Json(summary)))
+}
+
+async fn close_table(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<TableId>,
+) -> Result<StatusCode, ApiError> {
+    require_admin(&state, &headers)?;
+    let handle = state.lobby.remove_table(id).ok_or(ApiError::TableNotFound)?;
+    handle.send(TableCommand::Close).await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn player_info(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<PlayerInfo>, ApiError> {
+    state.lobby.directory.get(&id).map(Json).ok_or(ApiError::PlayerNotFound)
+}
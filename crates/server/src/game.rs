@@ -1,303 +1,756 @@
+use crate::lobby::{admin_matches, PlayerDirectory};
 use holdem_shared::*;
 use std::collections::HashMap;
-use std::io::Write;
-use std::net::TcpStream;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
 
-pub type PlayerConnections = Arc<Mutex<HashMap<String, TcpStream>>>;
+/// 테이블 액터 명령 큐 크기
+const COMMAND_QUEUE: usize = 256;
 
-pub struct GameServer {
-    pub game_state: Arc<Mutex<GameState>>,
-    pub connections: PlayerConnections,
+/// 연결이 끊긴 플레이어의 자리를 지켜 주는 시간. 그동안 차례가 오면 체크나 폴드를 대신 한다
+const RECONNECT_GRACE: Duration = Duration::from_secs(60);
+
+/// 연결로 만든 테이블이 빈 채로 버티는 시간. 지나면 테이블을 닫는다
+const EMPTY_TABLE_GRACE: Duration = Duration::from_secs(300);
+
+/// 한 핸드의 셔플 커밋과 클라이언트가 보탠 엔트로피
+pub struct FairShuffle {
+    pub commit: ShuffleCommit,
+    /// (플레이어 id, 엔트로피) - 보탠 순서대로
+    pub entropy: Vec<(String, String)>,
 }
 
-impl GameServer {
+impl FairShuffle {
     pub fn new() -> Self {
-        let game_state = GameState {
-            players: Vec::new(),
-            community_cards: Vec::new(),
-            pot: 0,
-            current_bet: 0,
-            phase: GamePhase::Waiting,
-            current_player_idx: 0,
-            dealer_idx: 0,
-        };
-
         Self {
-            game_state: Arc::new(Mutex::new(game_state)),
-            connections: Arc::new(Mutex::new(HashMap::new())),
+            commit: ShuffleCommit::generate(),
+            entropy: Vec::new(),
         }
     }
 
-    pub fn broadcast(&self, message: &ServerMessage, exclude_id: Option<&str>) {
-        let connections = self.connections.lock().unwrap();
-        let json = serde_json::to_string(message).unwrap() + "\n";
+    pub fn client_entropy(&self) -> Vec<String> {
+        self.entropy.iter().map(|(_, e)| e.clone()).collect()
+    }
+}
 
-        for (player_id, stream) in connections.iter() {
-            if let Some(exclude) = exclude_id {
-                if player_id == exclude {
-                    continue;
-                }
-            }
+/// 클라이언트 연결 하나. 같은 플레이어가 다시 접속하면 id가 다르다
+pub struct Connection {
+    pub id: String,
+    pub outbox: mpsc::Sender<ServerMessage>,
+}
 
-            if let Ok(mut stream) = stream.try_clone() {
-                let _ = stream.write_all(json.as_bytes());
-                let _ = stream.flush();
-            }
-        }
-    }
+/// 테이블 액터에 보내는 명령
+pub enum TableCommand {
+    /// 자리에 앉힌다. 앉으면 이 테이블이 보내는 메시지는 `connection`으로 가고,
+    /// 거절되면 이유를 연결로 알린 뒤 연결은 들고 있지 않는다. 앉았는지를 `reply`로 답한다
+    Join {
+        player_id: String,
+        name: String,
+        buy_in: Option<i32>,
+        connection: Connection,
+        reply: oneshot::Sender<bool>,
+    },
+    /// 앉아 있는 플레이어가 보낸 메시지
+    Message {
+        player_id: String,
+        message: ClientMessage,
+    },
+    /// 연결이 끊김. 자리는 `RECONNECT_GRACE` 동안 남겨 둔다
+    Disconnect {
+        player_id: String,
+        connection_id: String,
+    },
+    /// 다른 테이블로 옮김. 진행 중인 핸드가 끝나면 자리를 뺀다
+    Leave { player_id: String },
+    /// 끊겼던 플레이어가 새 연결로 돌아옴. 자리가 남아 있었는지를 `reply`로 답한다
+    Resume {
+        player_id: String,
+        connection: Connection,
+        reply: oneshot::Sender<bool>,
+    },
+    /// 로비 목록용 요약
+    Summary { reply: oneshot::Sender<TableSummary> },
+    /// 공개 상태
+    Detail { reply: oneshot::Sender<TableDetail> },
+    /// 테이블을 닫는다. 연결을 모두 끊고 액터가 끝난다
+    Close,
+}
 
-    pub fn send_to_player(&self, player_id: &str, message: &ServerMessage) {
-        let connections = self.connections.lock().unwrap();
-        if let Some(stream) = connections.get(player_id) {
-            if let Ok(mut stream) = stream.try_clone() {
-                let json = serde_json::to_string(message).unwrap() + "\n";
-                let _ = stream.write_all(json.as_bytes());
-                let _ = stream.flush();
-            }
-        }
+/// 테이블 액터 핸들. 복제해서 연결마다 하나씩 들고 있는다.
+#[derive(Clone)]
+pub struct TableHandle {
+    commands: mpsc::Sender<TableCommand>,
+    /// 테이블을 만든 플레이어 (플레이어별 테이블 수 제한에 쓴다)
+    pub owner: Option<String>,
+}
+
+impl TableHandle {
+    /// 테이블 액터를 띄우고 핸들을 돌려준다. 설정은 미리 검사해야 한다.
+    /// `owner`는 테이블을 만든 플레이어로, 관리자 토큰 없이도 설정을 바꿀 수 있다
+    pub fn spawn(
+        id: TableId,
+        config: TableConfig,
+        directory: Arc<PlayerDirectory>,
+        owner: Option<String>,
+        admin_token: Option<String>,
+    ) -> Self {
+        let (commands, inbox) = mpsc::channel(COMMAND_QUEUE);
+        let mut server = GameServer::new(id, config, directory);
+        server.owner = owner.clone();
+        server.admin_token = admin_token;
+        tokio::spawn(server.run(inbox));
+        Self { commands, owner }
     }
 
-    pub fn start_game(&self) {
-        let mut state = self.game_state.lock().unwrap();
+    /// 명령을 보낸다. 액터가 끝났으면 false
+    pub async fn send(&self, command: TableCommand) -> bool {
+        self.commands.send(command).await.is_ok()
+    }
 
-        if state.players.len() < 2 {
-            return;
-        }
+    /// 요약을 묻는다. 액터가 끝났으면 `None`
+    pub async fn summary(&self) -> Option<TableSummary> {
+        let (reply, answer) = oneshot::channel();
+        self.send(TableCommand::Summary { reply }).await;
+        answer.await.ok()
+    }
 
-        // 덱 생성 및 섞기
-        let mut deck = Deck::new();
-        deck.shuffle();
-
-        // 각 플레이어에게 2장씩 배분
-        for player in &mut state.players {
-            player.hand.clear();
-            player.hand.push(deck.deal().unwrap());
-            player.hand.push(deck.deal().unwrap());
-            player.folded = false;
-            player.bet = 0;
-        }
+    /// 공개 상태를 묻는다. 액터가 끝났으면 `None`
+    pub async fn detail(&self) -> Option<TableDetail> {
+        let (reply, answer) = oneshot::channel();
+        self.send(TableCommand::Detail { reply }).await;
+        answer.await.ok()
+    }
 
-        // 커뮤니티 카드 초기화
-        state.community_cards.clear();
-        state.pot = 0;
-        state.current_bet = 10;
-        state.phase = GamePhase::PreFlop;
-        state.current_player_idx = (state.dealer_idx + 1) % state.players.len();
+    /// 액터가 끝났는지 (닫혔거나 빈 채로 남아 스스로 닫음)
+    pub fn is_closed(&self) -> bool {
+        self.commands.is_closed()
+    }
 
-        // 블라인드 배팅
-        let small_blind_idx = (state.dealer_idx + 1) % state.players.len();
-        let big_blind_idx = (state.dealer_idx + 2) % state.players.len();
+    /// 자리에 앉힌다. 앉았는지를 돌려주고, 액터가 끝났으면 `None`
+    pub async fn join(
+        &self,
+        player_id: String,
+        name: String,
+        buy_in: Option<i32>,
+        connection: Connection,
+    ) -> Option<bool> {
+        let (reply, answer) = oneshot::channel();
+        self.send(TableCommand::Join {
+            player_id,
+            name,
+            buy_in,
+            connection,
+            reply,
+        })
+        .await;
+        answer.await.ok()
+    }
 
-        state.players[small_blind_idx].chips -= 5;
-        state.players[small_blind_idx].bet = 5;
-        state.players[big_blind_idx].chips -= 10;
-        state.players[big_blind_idx].bet = 10;
-        state.pot = 15;
+    /// 끊겼던 자리로 돌아간다. 자리가 남아 있었는지를 돌려주고, 액터가 끝났으면 `None`
+    pub async fn resume(&self, player_id: String, connection: Connection) -> Option<bool> {
+        let (reply, answer) = oneshot::channel();
+        self.send(TableCommand::Resume {
+            player_id,
+            connection,
+            reply,
+        })
+        .await;
+        answer.await.ok()
+    }
+}
 
-        drop(state);
+/// 테이블 하나를 혼자 소유하는 액터. 명령을 하나씩 처리하므로 잠금이 필요 없다.
+pub struct GameServer {
+    pub id: TableId,
+    pub table: Table,
+    /// 플레이어별 연결
+    pub connections: HashMap<String, Connection>,
+    /// 연결이 끊긴 플레이어와 자리를 뺄 시각
+    pub disconnected: HashMap<String, Instant>,
+    /// 플레이어별 재접속 토큰
+    pub tokens: HashMap<String, String>,
+    /// 다음 핸드용 셔플 (커밋은 이미 공개됨)
+    pub next_shuffle: FairShuffle,
+    /// 진행 중인 핸드의 셔플 (핸드가 끝나면 공개)
+    pub current_shuffle: Option<FairShuffle>,
+    /// 끝난 핸드 수
+    pub hands_played: u64,
+    /// 칩과 핸드 기록을 남기는 곳
+    pub directory: Arc<PlayerDirectory>,
+    /// 테이블을 만든 플레이어. 있으면 빈 채로 `EMPTY_TABLE_GRACE`가 지났을 때 테이블을 닫는다
+    pub owner: Option<String>,
+    /// 빈 테이블을 닫을 시각
+    pub empty_deadline: Option<Instant>,
+    /// 서버 관리자 토큰 (없으면 관리자 권한을 쓸 수 없다)
+    pub admin_token: Option<String>,
+}
 
-        // 각 플레이어에게 카드 전송
-        let state = self.game_state.lock().unwrap();
-        for player in &state.players {
-            self.send_to_player(
-                &player.id,
-                &ServerMessage::DealCards {
-                    cards: player.hand.clone(),
-                },
-            );
+impl GameServer {
+    pub fn new(id: TableId, config: TableConfig, directory: Arc<PlayerDirectory>) -> Self {
+        Self {
+            id,
+            table: Table::with_config(config).expect("테이블 설정은 만들기 전에 검사한다"),
+            connections: HashMap::new(),
+            disconnected: HashMap::new(),
+            tokens: HashMap::new(),
+            next_shuffle: FairShuffle::new(),
+            current_shuffle: None,
+            hands_played: 0,
+            directory,
+            owner: None,
+            empty_deadline: None,
+            admin_token: None,
         }
+    }
 
-        self.broadcast(&ServerMessage::GameState(state.clone()), None);
+    /// 명령 큐가 닫힐 때까지 명령을 처리한다. 재접속 유예 시간이 지나면 자리를 정리한다.
+    pub async fn run(mut self, mut inbox: mpsc::Receiver<TableCommand>) {
+        loop {
+            self.track_empty();
+            if self.empty_deadline.is_some_and(|deadline| deadline <= Instant::now()) {
+                println!("🧹 테이블 {} 빈 채로 남아 닫음", self.id);
+                self.close();
+                break;
+            }
+
+            let deadline = self.next_deadline();
+            let grace_over = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline).await,
+                    None => std::future::pending().await,
+                }
+            };
+            let command = tokio::select! {
+                command = inbox.recv() => command,
+                _ = grace_over => {
+                    self.remove_departed();
+                    continue;
+                }
+            };
+            let Some(command) = command else {
+                break;
+            };
+
+            match command {
+                TableCommand::Join {
+                    player_id,
+                    name,
+                    buy_in,
+                    connection,
+                    reply,
+                } => {
+                    self.connections.insert(player_id.clone(), connection);
+                    let seated = self.join(&player_id, name, buy_in);
+                    if !seated {
+                        // 거절 메시지는 이미 큐에 넣었다
+                        self.connections.remove(&player_id);
+                    }
+                    let _ = reply.send(seated);
+                }
+                TableCommand::Message { player_id, message } => {
+                    self.handle_message(&player_id, message);
+                }
+                TableCommand::Disconnect {
+                    player_id,
+                    connection_id,
+                } => self.disconnect(&player_id, &connection_id),
+                TableCommand::Leave { player_id } => self.leave(&player_id),
+                TableCommand::Resume {
+                    player_id,
+                    connection,
+                    reply,
+                } => {
+                    let _ = reply.send(self.resume(&player_id, connection));
+                }
+                TableCommand::Summary { reply } => {
+                    let _ = reply.send(self.summary());
+                }
+                TableCommand::Detail { reply } => {
+                    let _ = reply.send(TableDetail {
+                        summary: self.summary(),
+                        state: self.table.state().view_for(""),
+                    });
+                }
+                TableCommand::Close => {
+                    self.close();
+                    break;
+                }
+            }
+        }
+        println!("🚪 테이블 {} 종료", self.id);
     }
 
-    pub fn handle_player_action(&self, player_id: &str, action: ClientMessage) {
-        let mut state = self.game_state.lock().unwrap();
+    pub fn summary(&self) -> TableSummary {
+        let state = self.table.state();
+        TableSummary {
+            id: self.id,
+            config: self.table.config().clone(),
+            seated: state.players.len(),
+            phase: state.phase.clone(),
+            hands_played: self.hands_played,
+        }
+    }
 
-        let player_idx = match state.players.iter().position(|p| p.id == player_id) {
-            Some(idx) => idx,
-            None => return,
-        };
+    /// 모두에게 알리고 연결을 끊는다. 큐를 버리면 각 연결의 쓰기가 끝난다
+    fn close(&mut self) {
+        self.broadcast(
+            &ServerMessage::Error {
+                message: "테이블이 닫혔습니다".to_string(),
+            },
+            None,
+        );
+        for player in &self.table.state().players {
+            self.directory.left(&player.id, self.id);
+        }
+        self.connections.clear();
+    }
 
-        if player_idx != state.current_player_idx {
-            drop(state);
+    fn handle_message(&mut self, player_id: &str, message: ClientMessage) {
+        // 자리에 앉은 플레이어만 테이블에 명령할 수 있다
+        if !self.is_seated(player_id) {
             self.send_to_player(
                 player_id,
-                &ServerMessage::Error {
-                    message: "당신의 턴이 아닙니다".to_string(),
+                ServerMessage::Error {
+                    message: "테이블에 앉아 있지 않습니다".to_string(),
                 },
             );
             return;
         }
 
-        let action_str = match action {
-            ClientMessage::Fold => {
-                state.players[player_idx].folded = true;
-                "폴드".to_string()
+        match message {
+            ClientMessage::Join { name, buy_in, .. } => {
+                self.join(player_id, name, buy_in);
             }
-            ClientMessage::Check => {
-                if state.players[player_idx].bet < state.current_bet {
-                    drop(state);
-                    self.send_to_player(
-                        player_id,
-                        &ServerMessage::Error {
-                            message: "체크할 수 없습니다".to_string(),
-                        },
-                    );
-                    return;
+            ClientMessage::AddEntropy { entropy } => {
+                self.add_entropy(player_id, entropy);
+            }
+            ClientMessage::SetVariant { variant, admin_token } => {
+                if self.authorize(player_id, admin_token.as_deref()) {
+                    self.set_variant(player_id, variant);
+                }
+            }
+            ClientMessage::SetConfig { config, admin_token } => {
+                if self.authorize(player_id, admin_token.as_deref()) {
+                    self.set_config(player_id, config);
                 }
-                "체크".to_string()
             }
-            ClientMessage::Call => {
-                let call_amount = state.current_bet - state.players[player_idx].bet;
-                state.players[player_idx].chips -= call_amount;
-                state.players[player_idx].bet = state.current_bet;
-                state.pot += call_amount;
-                "콜".to_string()
+            ClientMessage::Ready => {
+                println!("🎮 게임 시작 요청");
+                self.start_game(player_id);
             }
-            ClientMessage::Raise { amount } => {
-                let total_bet = state.current_bet + amount;
-                let raise_amount = total_bet - state.players[player_idx].bet;
-                state.players[player_idx].chips -= raise_amount;
-                state.players[player_idx].bet = total_bet;
-                state.current_bet = total_bet;
-                state.pot += raise_amount;
-                format!("레이즈 {}", amount)
+            _ => {
+                self.handle_player_action(player_id, message);
             }
-            _ => return,
-        };
+        }
+    }
 
-        self.broadcast(
-            &ServerMessage::PlayerAction {
+    /// 테이블을 만든 플레이어이거나 관리자 토큰이 맞는지. 아니면 거절을 알린다
+    fn authorize(&mut self, player_id: &str, admin_token: Option<&str>) -> bool {
+        let owner = self.owner.as_deref() == Some(player_id);
+        if owner || admin_matches(self.admin_token.as_deref(), admin_token) {
+            return true;
+        }
+        self.send_to_player(
+            player_id,
+            ServerMessage::Error {
+                message: "테이블을 만든 플레이어나 관리자만 설정을 바꿀 수 있습니다".to_string(),
+            },
+        );
+        false
+    }
+
+    /// 자리에 앉힌다. 거절되면 이유를 보내고 false
+    fn join(&mut self, player_id: &str, name: String, buy_in: Option<i32>) -> bool {
+        let config = self.table.config().clone();
+        let chips = buy_in.unwrap_or(config.starting_stack);
+        let player = Player::new(player_id.to_string(), name.clone(), chips);
+        if let Err(error) = self.table.seat_player(player.clone()) {
+            self.send_to_player(player_id, ServerMessage::ActionRejected { error });
+            return false;
+        }
+        let token = self.directory.seated(&player, self.id);
+        self.tokens.insert(player_id.to_string(), token.clone());
+
+        self.send_to_player(
+            player_id,
+            ServerMessage::Welcome {
                 player_id: player_id.to_string(),
-                action: action_str,
+                chips,
+                token,
+            },
+        );
+        self.send_to_player(
+            player_id,
+            ServerMessage::TableInfo {
+                table_id: self.id,
+                config,
+            },
+        );
+        self.send_to_player(
+            player_id,
+            ServerMessage::ShuffleCommitment {
+                commitment: self.shuffle_commitment(),
             },
-            None,
         );
+        self.broadcast_state();
 
-        // 다음 플레이어로 이동
-        loop {
-            state.current_player_idx = (state.current_player_idx + 1) % state.players.len();
-            if !state.players[state.current_player_idx].folded {
-                break;
-            }
+        println!("📝 {} 테이블 {} 참가", name, self.id);
+        true
+    }
+
+    fn is_seated(&self, player_id: &str) -> bool {
+        self.table.state().players.iter().any(|p| p.id == player_id)
+    }
+
+    /// 연결이 끊긴 플레이어는 유예 시간 동안 자리를 지키고, 차례가 오면 대신 액션한다.
+    fn disconnect(&mut self, player_id: &str, connection_id: &str) {
+        // 이미 새 연결로 돌아온 뒤 예전 연결이 끊긴 경우
+        if self
+            .connections
+            .get(player_id)
+            .is_some_and(|c| c.id != connection_id)
+        {
+            return;
+        }
+        self.connections.remove(player_id);
+        if !self.is_seated(player_id) {
+            return;
         }
 
-        // 베팅 라운드 종료 체크
-        let active_players: Vec<&Player> = state.players.iter().filter(|p| !p.folded).collect();
+        println!("📴 테이블 {} 연결 끊김: {}", self.id, player_id);
+        self.disconnected
+            .insert(player_id.to_string(), Instant::now() + RECONNECT_GRACE);
+        self.table.set_connected(player_id, false);
+        self.broadcast_state();
+        self.play_for_disconnected();
+    }
 
-        if active_players.len() == 1 {
-            let winner = active_players[0].clone();
-            let pot = state.pot;
-            drop(state);
-            self.end_game(&winner.id, pot);
+    /// 다른 테이블로 옮긴 플레이어. 핸드 중이면 남은 차례는 대신 접고 핸드가 끝나면 자리를 뺀다
+    fn leave(&mut self, player_id: &str) {
+        self.connections.remove(player_id);
+        if !self.is_seated(player_id) {
             return;
         }
+        self.disconnected.insert(player_id.to_string(), Instant::now());
+        self.table.set_connected(player_id, false);
+        self.play_for_disconnected();
+        self.remove_departed();
+        self.broadcast_state();
+    }
+
+    /// 끊겼던 플레이어를 새 연결에 다시 붙이고 홀카드와 상태를 보낸다. 자리가 없으면 false
+    fn resume(&mut self, player_id: &str, connection: Connection) -> bool {
+        let player = self.table.state().players.iter().find(|p| p.id == player_id).cloned();
+        let (Some(player), Some(token)) = (player, self.tokens.get(player_id).cloned()) else {
+            let _ = connection.outbox.try_send(ServerMessage::Error {
+                message: "세션이 만료되었습니다".to_string(),
+            });
+            return false;
+        };
 
-        let all_bets_equal = active_players.iter().all(|p| p.bet == state.current_bet);
+        println!("🔁 테이블 {} 재접속: {}", self.id, player_id);
+        self.disconnected.remove(player_id);
+        self.table.set_connected(player_id, true);
+        self.connections.insert(player_id.to_string(), connection);
 
-        if all_bets_equal {
-            drop(state);
-            self.next_phase();
-        } else {
-            self.broadcast(&ServerMessage::GameState(state.clone()), None);
+        self.send_to_player(
+            player_id,
+            ServerMessage::Welcome {
+                player_id: player_id.to_string(),
+                chips: player.chips,
+                token,
+            },
+        );
+        self.send_to_player(
+            player_id,
+            ServerMessage::TableInfo {
+                table_id: self.id,
+                config: self.table.config().clone(),
+            },
+        );
+        // 진행 중인 핸드의 커밋도 다시 보내야 핸드가 끝난 뒤 검증할 수 있다
+        if let Some(shuffle) = &self.current_shuffle {
+            let commitment = shuffle.commit.commitment();
+            self.send_to_player(player_id, ServerMessage::ShuffleCommitment { commitment });
+        }
+        self.send_to_player(
+            player_id,
+            ServerMessage::ShuffleCommitment {
+                commitment: self.shuffle_commitment(),
+            },
+        );
+        if self.table.state().is_betting() && !player.hand.is_empty() {
+            self.send_to_player(player_id, ServerMessage::DealCards { cards: player.hand });
         }
+        self.broadcast_state();
+        true
     }
 
-    pub fn next_phase(&self) {
-        let mut state = self.game_state.lock().unwrap();
-        let mut deck = Deck::new();
-        deck.shuffle();
+    /// 다음으로 자리를 정리하거나 빈 테이블을 닫을 시각.
+    /// 이미 지났지만 핸드가 끝나기를 기다리는 자리는 빼고 센다
+    fn next_deadline(&self) -> Option<Instant> {
+        let now = Instant::now();
+        let departed = self.disconnected.values().copied().filter(|d| *d > now).min();
+        departed.into_iter().chain(self.empty_deadline).min()
+    }
 
-        // 이미 사용된 카드 제거
-        for player in &state.players {
-            for card in &player.hand {
-                deck.cards.retain(|c| c != card);
-            }
+    /// 연결로 만든 테이블이 비면 닫을 시각을 정하고, 누가 앉으면 지운다
+    fn track_empty(&mut self) {
+        if self.owner.is_none() || !self.table.state().players.is_empty() {
+            self.empty_deadline = None;
+        } else if self.empty_deadline.is_none() {
+            self.empty_deadline = Some(Instant::now() + EMPTY_TABLE_GRACE);
+        }
+    }
+
+    /// 유예 시간이 지난 자리를 뺀다. 핸드 중이면 핸드가 끝난 뒤로 미룬다
+    fn remove_departed(&mut self) {
+        if self.table.state().is_betting() {
+            return;
         }
-        for card in &state.community_cards {
-            deck.cards.retain(|c| c != card);
+        let now = Instant::now();
+        let departed: Vec<String> = self
+            .disconnected
+            .iter()
+            .filter(|(_, deadline)| **deadline <= now)
+            .map(|(id, _)| id.clone())
+            .collect();
+        if departed.is_empty() {
+            return;
         }
 
-        match state.phase {
-            GamePhase::PreFlop => {
-                state.community_cards.push(deck.deal().unwrap());
-                state.community_cards.push(deck.deal().unwrap());
-                state.community_cards.push(deck.deal().unwrap());
-                state.phase = GamePhase::Flop;
-            }
-            GamePhase::Flop => {
-                state.community_cards.push(deck.deal().unwrap());
-                state.phase = GamePhase::Turn;
+        for player_id in &departed {
+            self.disconnected.remove(player_id);
+            self.tokens.remove(player_id);
+            self.connections.remove(player_id);
+            if self.table.remove_player(player_id).is_ok() {
+                self.directory.left(player_id, self.id);
+                println!("❌ 테이블 {} 자리 정리: {}", self.id, player_id);
             }
-            GamePhase::Turn => {
-                state.community_cards.push(deck.deal().unwrap());
-                state.phase = GamePhase::River;
-            }
-            GamePhase::River => {
-                state.phase = GamePhase::Showdown;
-                drop(state);
-                self.showdown();
-                return;
+        }
+        self.broadcast_state();
+    }
+
+    /// 차례가 된 플레이어의 연결이 끊겨 있으면 체크나 폴드를 대신 한다.
+    fn play_for_disconnected(&mut self) {
+        let state = self.table.state();
+        let Some(player) = state.players.get(state.current_player_idx) else {
+            return;
+        };
+        if !self.disconnected.contains_key(&player.id) {
+            return;
+        }
+        let player_id = player.id.clone();
+        let Some(action) = self.table.auto_action(&player_id) else {
+            return;
+        };
+        // 이벤트를 보내고 나면 다음 차례도 다시 확인한다
+        if let Ok(events) = self.table.apply(&player_id, action) {
+            self.dispatch(events);
+        }
+    }
+
+    /// 전송 큐에 메시지를 넣는다. 큐가 가득 찬 (느린) 클라이언트는 기다리지 않고 연결을 끊는다.
+    fn queue(&mut self, player_id: &str, message: ServerMessage) {
+        let Some(connection) = self.connections.get(player_id) else {
+            return;
+        };
+        if let Err(error) = connection.outbox.try_send(message) {
+            if let mpsc::error::TrySendError::Full(_) = error {
+                eprintln!("⚠️ 전송 큐가 가득 차 연결을 끊습니다: {}", player_id);
             }
-            _ => {}
+            // 큐를 버리면 쓰기 태스크가 끝나고 연결이 닫힌다
+            self.connections.remove(player_id);
         }
+    }
 
-        for player in &mut state.players {
-            player.bet = 0;
+    pub fn broadcast(&mut self, message: &ServerMessage, exclude_id: Option<&str>) {
+        let player_ids: Vec<String> = self
+            .connections
+            .keys()
+            .filter(|id| exclude_id != Some(id.as_str()))
+            .cloned()
+            .collect();
+        for player_id in player_ids {
+            self.queue(&player_id, message.clone());
         }
-        state.current_bet = 0;
-        state.current_player_idx = (state.dealer_idx + 1) % state.players.len();
+    }
 
-        self.broadcast(&ServerMessage::GameState(state.clone()), None);
+    pub fn send_to_player(&mut self, player_id: &str, message: ServerMessage) {
+        self.queue(player_id, message);
     }
 
-    pub fn showdown(&self) {
-        let state = self.game_state.lock().unwrap();
-        let mut best_player_id = String::new();
-        let mut best_value = HandValue {
-            rank: HandRank::HighCard,
-            values: vec![0],
-        };
+    /// 접속한 각 클라이언트에게 자기 시점의 게임 상태를 보낸다.
+    pub fn broadcast_state(&mut self) {
+        let player_ids: Vec<String> = self.connections.keys().cloned().collect();
+        for player_id in player_ids {
+            let message = ServerMessage::GameState(self.table.state().view_for(&player_id));
+            self.queue(&player_id, message);
+        }
+    }
 
-        for player in &state.players {
-            if player.folded {
-                continue;
+    /// 다음 핸드 셔플의 커밋 해시
+    pub fn shuffle_commitment(&self) -> String {
+        self.next_shuffle.commit.commitment()
+    }
+
+    /// 다음 핸드 셔플에 플레이어 엔트로피를 보탠다. 플레이어당 하나만 유지한다.
+    pub fn add_entropy(&mut self, player_id: &str, entropy: String) {
+        if entropy.len() > MAX_ENTROPY_LEN {
+            self.send_to_player(
+                player_id,
+                ServerMessage::Error {
+                    message: format!("엔트로피는 {}바이트 이하여야 합니다", MAX_ENTROPY_LEN),
+                },
+            );
+            return;
+        }
+
+        let shuffle = &mut self.next_shuffle;
+        shuffle.entropy.retain(|(id, _)| id != player_id);
+        shuffle.entropy.push((player_id.to_string(), entropy));
+    }
+
+    /// 새 핸드를 시작한다. 시작할 수 없으면 요청한 플레이어에게 이유를 알린다
+    pub fn start_game(&mut self, player_id: &str) {
+        // 커밋된 시드와 클라이언트 엔트로피로 덱 생성
+        let deck = self.next_shuffle.commit.deck(&self.next_shuffle.client_entropy());
+
+        let events = match self.table.start_hand(deck) {
+            Ok(events) => events,
+            Err(error) => {
+                self.send_to_player(player_id, ServerMessage::ActionRejected { error });
+                return;
             }
+        };
+        // 이번 핸드 셔플은 끝날 때 공개하고, 다음 핸드 커밋을 바로 공개한다
+        let shuffle = std::mem::replace(&mut self.next_shuffle, FairShuffle::new());
+        self.current_shuffle = Some(shuffle);
 
-            let mut all_cards = player.hand.clone();
-            all_cards.extend(state.community_cards.clone());
+        let commitment = self.shuffle_commitment();
+        self.broadcast(&ServerMessage::ShuffleCommitment { commitment }, None);
+        self.dispatch(events);
+    }
 
-            let (_, value) = find_best_hand(&all_cards);
+    /// 핸드 사이에 테이블 게임 종류를 바꾸고 모두에게 알린다.
+    pub fn set_variant(&mut self, player_id: &str, variant: Variant) {
+        match self.table.set_variant(variant) {
+            Ok(()) => self.broadcast_state(),
+            Err(error) => self.send_to_player(player_id, ServerMessage::ActionRejected { error }),
+        }
+    }
 
-            if value > best_value {
-                best_value = value;
-                best_player_id = player.id.clone();
+    /// 핸드 사이에 테이블 설정을 바꾸고 모두에게 알린다.
+    pub fn set_config(&mut self, player_id: &str, config: TableConfig) {
+        match self.table.set_config(config) {
+            Ok(()) => {
+                let config = self.table.config().clone();
+                let table_id = self.id;
+                self.broadcast(&ServerMessage::TableInfo { table_id, config }, None);
+                self.broadcast_state();
             }
+            Err(error) => self.send_to_player(player_id, ServerMessage::ActionRejected { error }),
         }
+    }
 
-        let pot = state.pot;
-        drop(state);
+    pub fn handle_player_action(&mut self, player_id: &str, message: ClientMessage) {
+        let Some(action) = Action::from_message(&message) else {
+            return;
+        };
 
-        self.end_game(&best_player_id, pot);
+        match self.table.apply(player_id, action) {
+            Ok(events) => self.dispatch(events),
+            Err(TableError::UnknownPlayer) => {}
+            Err(error) => self.send_to_player(player_id, ServerMessage::ActionRejected { error }),
+        }
     }
 
-    pub fn end_game(&self, winner_id: &str, amount: i32) {
-        let mut state = self.game_state.lock().unwrap();
+    /// 테이블 이벤트를 클라이언트 메시지로 바꿔 전송하고, 마지막에 상태를 알린다.
+    fn dispatch(&mut self, events: Vec<TableEvent>) {
+        for event in events {
+            match event {
+                TableEvent::HoleCardsDealt { player_id, cards } => {
+                    self.send_to_player(&player_id, ServerMessage::DealCards { cards });
+                }
+                TableEvent::PlayerActed {
+                    player_id,
+                    action,
+                    paid,
+                    all_in,
+                } => {
+                    self.broadcast(
+                        &ServerMessage::PlayerAction {
+                            player_id,
+                            action: action.describe(paid, all_in),
+                        },
+                        None,
+                    );
+                }
+                TableEvent::HandEnded { winners, showdown } => {
+                    self.record_hand(&winners);
+                    self.broadcast(&ServerMessage::GameOver { winners, showdown }, None);
+                    self.reveal_shuffle();
+                }
+                TableEvent::HandStarted { .. }
+                | TableEvent::AntePosted { .. }
+                | TableEvent::BlindPosted { .. }
+                | TableEvent::PhaseChanged { .. } => {}
+            }
+        }
+
+        self.broadcast_state();
+        self.play_for_disconnected();
+        self.remove_departed();
+    }
 
-        if let Some(winner) = state.players.iter_mut().find(|p| p.id == winner_id) {
-            winner.chips += amount;
+    /// 핸드에 참가한 플레이어의 결과를 기록한다.
+    fn record_hand(&mut self, winners: &[Payout]) {
+        self.hands_played += 1;
+        for player in &self.table.state().players {
+            let won = winners
+                .iter()
+                .find(|w| w.player_id == player.id)
+                .map_or(0, |w| w.amount);
+            if player.contributed() == 0 && won == 0 {
+                continue;
+            }
+            let history = HandHistory {
+                table_id: self.id,
+                hand: self.hands_played,
+                net: won - player.contributed(),
+                chips: player.chips,
+            };
+            self.directory.record(player, history);
         }
+    }
 
-        self.broadcast(
-            &ServerMessage::GameOver {
-                winner_id: winner_id.to_string(),
-                amount,
-            },
-            None,
-        );
+    /// 끝난 핸드의 서버 시드와 딜 기록을 공개한다.
+    fn reveal_shuffle(&mut self) {
+        let Some(shuffle) = self.current_shuffle.take() else {
+            return;
+        };
 
-        state.phase = GamePhase::Waiting;
-        state.dealer_idx = (state.dealer_idx + 1) % state.players.len();
+        // 접거나 보여 주지 않은 홀카드는 본인에게만 공개한다
+        let shown: Vec<String> = self
+            .table
+            .state()
+            .players
+            .iter()
+            .filter(|p| p.show_cards)
+            .map(|p| p.id.clone())
+            .collect();
+        let player_ids: Vec<String> = self.connections.keys().cloned().collect();
+        for player_id in player_ids {
+            let reveal = ServerMessage::ShuffleReveal {
+                variant: self.table.variant(),
+                commitment: shuffle.commit.commitment(),
+                server_seed: shuffle.commit.reveal(),
+                client_entropy: shuffle.client_entropy(),
+                deal_log: visible_deals(self.table.deal_log(), &player_id, &shown),
+            };
+            self.queue(&player_id, reveal);
+        }
     }
-}
\ No newline at end of file
+}
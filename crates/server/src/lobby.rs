@@ -0,0 +1,201 @@
+use crate::game::TableHandle;
+use holdem_shared::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::{Arc, Mutex, RwLock};
+use uuid::Uuid;
+
+/// 앉아 있는 플레이어별 칩과 핸드 기록, 재접속 토큰. 테이블 액터가 갱신하고 REST API와 연결이 읽는다.
+#[derive(Default)]
+pub struct PlayerDirectory {
+    players: Mutex<HashMap<String, PlayerInfo>>,
+    /// 재접속 토큰 -> 플레이어 id (REST API로는 공개하지 않는다)
+    tokens: Mutex<HashMap<String, String>>,
+}
+
+impl PlayerDirectory {
+    /// 테이블에 앉았을 때. 재접속 토큰을 새로 발급한다
+    pub fn seated(&self, player: &Player, table_id: TableId) -> String {
+        let mut players = self.players.lock().unwrap();
+        let info = players.entry(player.id.clone()).or_insert_with(|| PlayerInfo {
+            id: player.id.clone(),
+            name: player.name.clone(),
+            table_id: None,
+            chips: 0,
+            history: Vec::new(),
+        });
+        info.table_id = Some(table_id);
+        info.chips = player.chips;
+        drop(players);
+
+        let token = Uuid::new_v4().simple().to_string();
+        let mut tokens = self.tokens.lock().unwrap();
+        tokens.retain(|_, id| *id != player.id);
+        tokens.insert(token.clone(), player.id.clone());
+        token
+    }
+
+    /// 토큰의 주인과 그 플레이어가 앉은 테이블
+    pub fn resolve(&self, token: &str) -> Option<(String, TableId)> {
+        let player_id = self.tokens.lock().unwrap().get(token).cloned()?;
+        let table_id = self.players.lock().unwrap().get(&player_id)?.table_id?;
+        Some((player_id, table_id))
+    }
+
+    /// 핸드가 끝났을 때 결과를 남긴다.
+    pub fn record(&self, player: &Player, history: HandHistory) {
+        let mut players = self.players.lock().unwrap();
+        if let Some(info) = players.get_mut(&player.id) {
+            info.chips = player.chips;
+            info.history.push(history);
+        }
+    }
+
+    /// 테이블을 떠났을 때 (일어남, 재접속 유예 만료, 테이블 닫힘). 기록과 토큰을 모두 지워
+    /// 오래 도는 서버에 떠난 플레이어가 쌓이지 않게 한다.
+    /// 그 사이 다른 테이블에 앉았으면 그 자리는 건드리지 않는다
+    pub fn left(&self, player_id: &str, table_id: TableId) {
+        let mut players = self.players.lock().unwrap();
+        if players.get(player_id).and_then(|info| info.table_id) != Some(table_id) {
+            return;
+        }
+        players.remove(player_id);
+        drop(players);
+        self.tokens.lock().unwrap().retain(|_, id| id != player_id);
+    }
+
+    pub fn get(&self, player_id: &str) -> Option<PlayerInfo> {
+        self.players.lock().unwrap().get(player_id).cloned()
+    }
+}
+
+/// 서버에 한 번에 열어 둘 수 있는 테이블 수
+pub const MAX_TABLES: usize = 64;
+
+/// 플레이어 한 명이 만들어 열어 둘 수 있는 테이블 수
+pub const MAX_TABLES_PER_PLAYER: usize = 2;
+
+/// 테이블을 만들 수 없는 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LobbyError {
+    InvalidConfig(ConfigError),
+    /// 열린 테이블이 이미 `MAX_TABLES`개
+    TooManyTables { max: usize },
+    /// 이 플레이어가 만든 테이블이 이미 `MAX_TABLES_PER_PLAYER`개
+    TooManyOwnTables { max: usize },
+}
+
+impl fmt::Display for LobbyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LobbyError::InvalidConfig(error) => error.fmt(f),
+            LobbyError::TooManyTables { max } => {
+                write!(f, "테이블이 너무 많습니다 (최대 {}개)", max)
+            }
+            LobbyError::TooManyOwnTables { max } => {
+                write!(f, "한 사람이 열어 둘 수 있는 테이블은 {}개까지입니다", max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LobbyError {}
+
+impl From<ConfigError> for LobbyError {
+    fn from(error: ConfigError) -> Self {
+        LobbyError::InvalidConfig(error)
+    }
+}
+
+/// 관리자 토큰이 설정되어 있고 주어진 토큰과 같은지
+pub fn admin_matches(expected: Option<&str>, given: Option<&str>) -> bool {
+    expected.is_some() && expected == given
+}
+
+/// 서버의 모든 테이블
+pub struct Lobby {
+    tables: RwLock<BTreeMap<TableId, TableHandle>>,
+    next_id: Mutex<TableId>,
+    pub directory: Arc<PlayerDirectory>,
+    /// 관리자 토큰. 없으면 관리자 권한이 필요한 일은 모두 막는다
+    admin_token: Option<String>,
+}
+
+impl Lobby {
+    pub fn new(admin_token: Option<String>) -> Self {
+        Self {
+            tables: RwLock::new(BTreeMap::new()),
+            next_id: Mutex::new(1),
+            directory: Default::default(),
+            admin_token,
+        }
+    }
+
+    pub fn admin_enabled(&self) -> bool {
+        self.admin_token.is_some()
+    }
+
+    /// REST 헤더나 클라이언트 메시지로 받은 토큰이 관리자 토큰인지
+    pub fn is_admin(&self, given: Option<&str>) -> bool {
+        admin_matches(self.admin_token.as_deref(), given)
+    }
+
+    /// 설정을 검사하고 새 테이블 액터를 띄운다. 스스로 닫힌 테이블은 이때 목록에서 뺀다.
+    /// `owner`는 테이블을 만든 플레이어로, 관리자 토큰 없이도 그 테이블 설정을 바꿀 수 있다
+    pub fn create_table(
+        &self,
+        config: TableConfig,
+        owner: Option<String>,
+    ) -> Result<TableId, LobbyError> {
+        config.validate()?;
+        let mut tables = self.tables.write().unwrap();
+        tables.retain(|_, table| !table.is_closed());
+        if tables.len() >= MAX_TABLES {
+            return Err(LobbyError::TooManyTables { max: MAX_TABLES });
+        }
+        if owner.is_some() {
+            let owned = tables.values().filter(|table| table.owner == owner).count();
+            if owned >= MAX_TABLES_PER_PLAYER {
+                return Err(LobbyError::TooManyOwnTables {
+                    max: MAX_TABLES_PER_PLAYER,
+                });
+            }
+        }
+        let id = {
+            let mut next_id = self.next_id.lock().unwrap();
+            let id = *next_id;
+            *next_id += 1;
+            id
+        };
+        let handle = TableHandle::spawn(
+            id,
+            config,
+            self.directory.clone(),
+            owner,
+            self.admin_token.clone(),
+        );
+        tables.insert(id, handle);
+        println!("🆕 테이블 {} 생성", id);
+        Ok(id)
+    }
+
+    pub fn table(&self, id: TableId) -> Option<TableHandle> {
+        self.tables.read().unwrap().get(&id).cloned()
+    }
+
+    /// 테이블을 정하지 않은 `Join`이 앉을 기본 테이블 (가장 먼저 만든 테이블)
+    pub fn default_table(&self) -> Option<(TableId, TableHandle)> {
+        let tables = self.tables.read().unwrap();
+        tables.iter().next().map(|(id, table)| (*id, table.clone()))
+    }
+
+    pub fn tables(&self) -> Vec<TableHandle> {
+        let tables = self.tables.read().unwrap();
+        tables.values().filter(|table| !table.is_closed()).cloned().collect()
+    }
+
+    /// 목록에서 빼고 핸들을 돌려준다. 액터를 끝내는 것은 호출한 쪽 몫이다
+    pub fn remove_table(&self, id: TableId) -> Option<TableHandle> {
+        self.tables.write().unwrap().remove(&id)
+    }
+}
@@ -1,29 +1,63 @@
+mod api;
 mod game;
+mod lobby;
 mod network;
+mod session;
+mod ws;
 
-use game::GameServer;
+use api::AppState;
+use holdem_shared::{TableConfig, Variant};
+use lobby::Lobby;
 use network::handle_client;
-use std::net::TcpListener;
 use std::sync::Arc;
-use std::thread;
+use tokio::net::TcpListener;
 
-fn main() {
-    let listener = TcpListener::bind("127.0.0.1:7878").unwrap();
-    println!("🃏 텍사스 홀덤 서버 시작: 127.0.0.1:7878");
+#[tokio::main]
+async fn main() {
+    // 첫 번째 인자로 기본 테이블의 게임 종류를 고른다
+    // (holdem, omaha, omaha-hilo, short-deck, one-to-ten)
+    let variant = match std::env::args().nth(1) {
+        Some(code) => Variant::from_code(&code).unwrap_or_else(|| {
+            let codes: Vec<&str> = Variant::ALL.iter().map(|v| v.code()).collect();
+            eprintln!("알 수 없는 게임 종류: {} (가능: {})", code, codes.join(", "));
+            std::process::exit(1);
+        }),
+        None => Variant::Holdem,
+    };
 
-    let server = Arc::new(GameServer::new());
+    let listener = TcpListener::bind("127.0.0.1:7878").await.unwrap();
+    let http_listener = TcpListener::bind("127.0.0.1:7879").await.unwrap();
+    println!("🃏 {} 서버 시작: 127.0.0.1:7878 (HTTP/WebSocket: http://127.0.0.1:7879)", variant);
 
-    for stream in listener.incoming() {
-        match stream {
-            Ok(stream) => {
-                let server_clone = Arc::clone(&server);
-                thread::spawn(move || {
-                    handle_client(stream, server_clone);
-                });
+    let admin_token = std::env::var("HOLDEM_ADMIN_TOKEN").ok().filter(|t| !t.is_empty());
+    if admin_token.is_none() {
+        println!("ℹ️ HOLDEM_ADMIN_TOKEN이 없어 테이블 생성/삭제 API는 꺼져 있습니다");
+    }
+
+    // 테이블마다 액터 태스크가 상태를 소유하고, 연결마다 태스크를 띄운다
+    let lobby = Arc::new(Lobby::new(admin_token));
+    lobby
+        .create_table(TableConfig::for_variant(variant), None)
+        .expect("게임 종류별 기본 설정은 항상 유효하다");
+
+    // REST API와 WebSocket은 axum이 받는다
+    let router = api::router(AppState {
+        lobby: lobby.clone(),
+    });
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(http_listener, router).await {
+            eprintln!("HTTP 서버 에러: {}", e);
+        }
+    });
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                tokio::spawn(handle_client(stream, lobby.clone()));
             }
             Err(e) => {
                 eprintln!("연결 에러: {}", e);
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -1,80 +1,43 @@
-use crate::game::GameServer;
+use crate::lobby::Lobby;
+use crate::session::Session;
 use holdem_shared::*;
-use std::io::{BufRead, BufReader};
-use std::net::TcpStream;
 use std::sync::Arc;
-use uuid::Uuid;
-
-pub fn handle_client(stream: TcpStream, server: Arc<GameServer>) {
-    let player_id = Uuid::new_v4().to_string();
-    let mut reader = BufReader::new(stream.try_clone().unwrap());
-
-    // 연결 저장
-    {
-        let mut connections = server.connections.lock().unwrap();
-        connections.insert(player_id.clone(), stream.try_clone().unwrap());
-    }
-
-    println!("✅ 새 플레이어 연결: {}", player_id);
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+pub async fn handle_client(stream: TcpStream, lobby: Arc<Lobby>) {
+    let (mut session, mut queue) = Session::new(lobby);
+    let (reader, mut writer) = stream.into_split();
+
+    // 쓰기는 별도 태스크가 큐에서 꺼내 보낸다
+    let mut writer_task = tokio::spawn(async move {
+        while let Some(message) = queue.recv().await {
+            let json = serde_json::to_string(&message).unwrap() + "\n";
+            if writer.write_all(json.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
 
+    let mut lines = BufReader::new(reader).lines();
     loop {
-        let mut line = String::new();
-        match reader.read_line(&mut line) {
-            Ok(0) => break, // 연결 종료
-            Ok(_) => {
-                if let Ok(message) = serde_json::from_str::<ClientMessage>(&line) {
-                    handle_message(&server, &player_id, message);
+        tokio::select! {
+            line = lines.next_line() => {
+                let Ok(Some(line)) = line else {
+                    break; // 연결 종료
+                };
+                if let Ok(message) = serde_json::from_str::<ClientMessage>(&line)
+                    && !session.handle(message).await
+                {
+                    break;
                 }
             }
-            Err(_) => break,
+            // 테이블이 큐를 버렸으면 (느린 클라이언트) 읽기도 그만둔다
+            _ = &mut writer_task => break,
         }
     }
 
     // 연결 종료 처리
-    cleanup_player(&server, &player_id);
+    session.close().await;
+    writer_task.abort();
 }
-
-fn handle_message(server: &Arc<GameServer>, player_id: &str, message: ClientMessage) {
-    match message {
-        ClientMessage::Join { name } => {
-            let mut state = server.game_state.lock().unwrap();
-            let player = Player::new(player_id.to_string(), name.clone(), 1000);
-            state.players.push(player);
-            drop(state);
-
-            server.send_to_player(
-                player_id,
-                &ServerMessage::Welcome {
-                    player_id: player_id.to_string(),
-                    chips: 1000,
-                },
-            );
-
-            let state = server.game_state.lock().unwrap();
-            server.broadcast(&ServerMessage::GameState(state.clone()), None);
-            
-            println!("📝 {} 참가", name);
-        }
-        ClientMessage::Ready => {
-            println!("🎮 게임 시작 요청");
-            server.start_game();
-        }
-        _ => {
-            server.handle_player_action(player_id, message);
-        }
-    }
-}
-
-fn cleanup_player(server: &Arc<GameServer>, player_id: &str) {
-    {
-        let mut connections = server.connections.lock().unwrap();
-        connections.remove(player_id);
-    }
-
-    {
-        let mut state = server.game_state.lock().unwrap();
-        state.players.retain(|p| p.id != player_id);
-    }
-
-    println!("❌ 플레이어 연결 종료: {}", player_id);
-}
\ No newline at end of file
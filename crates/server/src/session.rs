@@ -0,0 +1,199 @@
+use crate::game::{Connection, TableCommand, TableHandle};
+use crate::lobby::Lobby;
+use holdem_shared::*;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// 연결별 전송 큐 크기. 이만큼 밀린 클라이언트는 테이블이 연결을 끊는다
+const OUTBOX_QUEUE: usize = 64;
+
+/// 연결 하나의 상태. 전송 방식(TCP, WebSocket)과 상관없이 메시지를 앉은 테이블로 보낸다.
+///
+/// 테이블에 앉아 있는 동안 전송 큐의 송신자는 그 테이블만 들고 있는다.
+/// 테이블이 느린 클라이언트의 큐를 버리면 큐가 닫히고 연결도 끝난다.
+pub struct Session {
+    /// 연결 id. 재접속하기 전에는 플레이어 id로도 쓴다
+    pub connection_id: String,
+    pub player_id: String,
+    lobby: Arc<Lobby>,
+    outbox: mpsc::WeakSender<ServerMessage>,
+    /// 테이블에 앉기 전에만 들고 있는 송신자
+    idle_outbox: Option<mpsc::Sender<ServerMessage>>,
+    table: Option<(TableId, TableHandle)>,
+}
+
+impl Session {
+    /// 세션과 전송 큐의 수신자를 만든다. 수신자가 `None`을 내면 연결을 닫으면 된다
+    pub fn new(lobby: Arc<Lobby>) -> (Self, mpsc::Receiver<ServerMessage>) {
+        let (outbox, queue) = mpsc::channel(OUTBOX_QUEUE);
+        let connection_id = Uuid::new_v4().to_string();
+        let session = Self {
+            player_id: connection_id.clone(),
+            connection_id,
+            lobby,
+            outbox: outbox.downgrade(),
+            idle_outbox: Some(outbox),
+            table: None,
+        };
+        (session, queue)
+    }
+
+    /// 테이블을 거치지 않는 응답
+    fn reply(&self, message: ServerMessage) {
+        if let Some(outbox) = self.outbox.upgrade() {
+            let _ = outbox.try_send(message);
+        }
+    }
+
+    fn reply_error(&self, message: &str) {
+        self.reply(ServerMessage::Error {
+            message: message.to_string(),
+        });
+    }
+
+    /// 클라이언트 메시지 하나를 처리한다. 연결을 닫아야 하면 false
+    pub async fn handle(&mut self, message: ClientMessage) -> bool {
+        match message {
+            ClientMessage::CreateTable { config } => {
+                // 누구나 만들 수 있지만 전체/플레이어별 테이블 수는 로비가 제한한다
+                match self.lobby.create_table(config.clone(), Some(self.player_id.clone())) {
+                    Ok(table_id) => self.reply(ServerMessage::TableCreated { table_id, config }),
+                    Err(error) => self.reply_error(&error.to_string()),
+                }
+                true
+            }
+            ClientMessage::Resume { token } => {
+                let Some((player_id, table_id)) = self.lobby.directory.resolve(&token) else {
+                    self.reply_error("세션이 만료되었습니다");
+                    return true;
+                };
+                let Some(table) = self.lobby.table(table_id) else {
+                    self.reply_error("테이블이 없습니다");
+                    return true;
+                };
+                self.resume(player_id, table_id, table).await
+            }
+            ClientMessage::Join { table_id, .. } => {
+                let table = match table_id {
+                    Some(id) => self.lobby.table(id).map(|table| (id, table)),
+                    None => self.lobby.default_table(),
+                };
+                let Some((table_id, table)) = table else {
+                    self.reply_error("테이블이 없습니다");
+                    return true;
+                };
+                if self.table.as_ref().is_some_and(|(id, _)| *id == table_id) {
+                    return self.forward(message).await;
+                }
+                self.sit(table_id, table, message).await
+            }
+            _ if self.table.is_none() => {
+                self.reply_error("테이블에 앉아 있지 않습니다");
+                true
+            }
+            _ => self.forward(message).await,
+        }
+    }
+
+    /// 앉은 테이블로 보낸다.
+    async fn forward(&mut self, message: ClientMessage) -> bool {
+        let Some((_, table)) = &self.table else {
+            return true;
+        };
+        let command = TableCommand::Message {
+            player_id: self.player_id.clone(),
+            message,
+        };
+        if !table.send(command).await {
+            // 테이블이 닫혔다
+            self.table = None;
+            self.idle_outbox = self.outbox.upgrade();
+            return self.idle_outbox.is_some();
+        }
+        true
+    }
+
+    /// 테이블에 넘겨줄 연결과, 테이블이 받지 않았을 때 다시 들고 있을 송신자.
+    /// 이전 테이블이 큐를 버렸으면 `None`
+    fn take_connection(&mut self) -> Option<(Connection, mpsc::Sender<ServerMessage>)> {
+        let outbox = self.idle_outbox.take().or_else(|| self.outbox.upgrade())?;
+        let connection = Connection {
+            id: self.connection_id.clone(),
+            outbox: outbox.clone(),
+        };
+        Some((connection, outbox))
+    }
+
+    /// 테이블의 답을 반영한다. 받아 줬으면 앉아 있던 테이블에서 일어나고 송신자는 새 테이블만 들고 있는다.
+    /// 받지 않았으면 앉아 있던 테이블은 그대로 두고, 어디에도 앉아 있지 않으면 송신자를 다시 들고 있는다
+    async fn attach(
+        &mut self,
+        table_id: TableId,
+        table: TableHandle,
+        outbox: mpsc::Sender<ServerMessage>,
+        accepted: Option<bool>,
+    ) -> bool {
+        let Some(true) = accepted else {
+            if self.table.is_none() {
+                self.idle_outbox = Some(outbox);
+            }
+            if accepted.is_none() {
+                self.reply_error("테이블이 없습니다");
+            }
+            return false;
+        };
+        self.leave().await;
+        self.table = Some((table_id, table));
+        true
+    }
+
+    /// 새 테이블에 앉는다. 앉은 뒤에야 앉아 있던 테이블에서 일어나므로,
+    /// 거절되면 (자리 없음, 바이인 범위 밖 등) 원래 자리가 그대로 남는다
+    async fn sit(&mut self, table_id: TableId, table: TableHandle, join: ClientMessage) -> bool {
+        let ClientMessage::Join { name, buy_in, .. } = join else {
+            return true;
+        };
+        let Some((connection, outbox)) = self.take_connection() else {
+            return false;
+        };
+        let accepted = table.join(self.player_id.clone(), name, buy_in, connection).await;
+        self.attach(table_id, table, outbox, accepted).await;
+        true
+    }
+
+    /// 토큰 주인의 자리로 돌아간다. 이후 이 연결은 그 플레이어로 행동한다
+    async fn resume(&mut self, player_id: String, table_id: TableId, table: TableHandle) -> bool {
+        let Some((connection, outbox)) = self.take_connection() else {
+            return false;
+        };
+        let accepted = table.resume(player_id.clone(), connection).await;
+        if self.attach(table_id, table, outbox, accepted).await {
+            self.player_id = player_id;
+        }
+        true
+    }
+
+    /// 다른 테이블로 옮기면서 앉아 있던 테이블에서 일어난다.
+    async fn leave(&mut self) {
+        if let Some((_, table)) = self.table.take() {
+            table
+                .send(TableCommand::Leave {
+                    player_id: self.player_id.clone(),
+                })
+                .await;
+        }
+    }
+
+    /// 연결 종료 처리. 자리는 재접속 유예 시간 동안 남는다
+    pub async fn close(mut self) {
+        if let Some((_, table)) = self.table.take() {
+            table
+                .send(TableCommand::Disconnect {
+                    player_id: self.player_id,
+                    connection_id: self.connection_id,
+                })
+                .await;
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::api::AppState;
+use crate::session::Session;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::State;
+use axum::response::Response;
+use holdem_shared::*;
+
+/// WebSocket 엔드포인트 (`/ws`). 텍스트 프레임 하나에 JSON 메시지 하나를 싣는다 (TCP와 같은 프로토콜).
+pub async fn upgrade(ws: WebSocketUpgrade, State(state): State<AppState>) -> Response {
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+async fn handle_socket(mut socket: WebSocket, state: AppState) {
+    let (mut session, mut queue) = Session::new(state.lobby);
+
+    loop {
+        tokio::select! {
+            message = queue.recv() => {
+                // 테이블이 큐를 버렸으면 (느린 클라이언트) 연결을 닫는다
+                let Some(message) = message else {
+                    break;
+                };
+                let json = serde_json::to_string(&message).unwrap();
+                if socket.send(Message::Text(json.into())).await.is_err() {
+                    break;
+                }
+            }
+            frame = socket.recv() => {
+                match frame {
+                    Some(Ok(Message::Text(text))) => {
+                        if let Ok(message) = serde_json::from_str::<ClientMessage>(&text)
+                            && !session.handle(message).await
+                        {
+                            break;
+                        }
+                    }
+                    // 핑/퐁은 axum이 처리한다
+                    Some(Ok(Message::Binary(_) | Message::Ping(_) | Message::Pong(_))) => {}
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                }
+            }
+        }
+    }
+
+    // 연결 종료 처리
+    session.close().await;
+}
@@ -0,0 +1,109 @@
+//! docs/README*.md의 확률 표를 코드로 다시 계산해 출력한다.
+//!
+//! ```text
+//! cargo run -p holdem-shared --bin odds_tables            # 1~10 하우스 게임
+//! cargo run -p holdem-shared --release --bin odds_tables -- --poker   # 52장 포커 표까지
+//! ```
+
+use holdem_shared::combinatorics::{multichoose, multiset_permutations};
+use holdem_shared::*;
+
+/// (문서 이름, 문서에 적힌 기약분수)
+type Claims<'a> = &'a [(&'a str, (u64, u64))];
+
+/// 문서에 적힌 값과 비교해 한 줄 출력한다.
+fn row(label: &str, actual: Probability, claims: Claims) {
+    let checks: Vec<String> = claims
+        .iter()
+        .map(|(doc, claim)| {
+            let mark = if actual.reduced() == *claim { "✓" } else { "✗" };
+            format!("{} {}/{} {}", doc, claim.0, claim.1, mark)
+        })
+        .collect();
+    println!("  {:<16} {:>8} / {:<8} {:<20} {}", label, actual.hits, actual.total, actual.to_string(), checks.join(", "));
+}
+
+fn same_rank(hand: &[Card]) -> bool {
+    hand.windows(2).all(|w| w[0].rank == w[1].rank)
+}
+
+fn same_suit(hand: &[Card]) -> bool {
+    hand.windows(2).all(|w| w[0].suit == w[1].suit)
+}
+
+fn print_category_table(variant: Variant, deck: &[Card], k: usize, classify: impl FnMut(&[Card]) -> HandRank) {
+    let mut odds = category_odds(deck, k, classify);
+    odds.sort_by_key(|o| std::cmp::Reverse(variant.category_order(o.rank)));
+    for o in odds {
+        row(o.rank.name(), o.probability, &[]);
+    }
+}
+
+fn main() {
+    let poker = std::env::args().any(|a| a == "--poker");
+
+    let house = Deck::from_spec(&Variant::OneToTen.deck_spec()).cards;
+    println!("== 1~10 하우스 게임: {}장 (숫자 1~10 × 4장) ==", house.len());
+
+    println!("\n[2장]");
+    row(
+        "같은 숫자 2장",
+        event_probability(&house, 2, same_rank),
+        &[("README", (1, 13)), ("README2", (2, 11))],
+    );
+    row("같은 무늬 2장", event_probability(&house, 2, same_suit), &[("README", (3, 13))]);
+
+    println!("\n[3장: 플레이어 1장 + 데스크 2장]");
+    let claims: [(HandRank, Claims); 3] = [
+        (HandRank::ThreeOfAKind, &[("README2", (1, 12))]),
+        (HandRank::Straight, &[("README2", (1, 15))]),
+        (HandRank::OnePair, &[("README2", (3, 4))]),
+    ];
+    let mut odds = category_odds(&house, 3, |hand| {
+        best_one_to_ten_hand(&hand[..1], &hand[1..]).unwrap().1.rank
+    });
+    odds.sort_by_key(|o| std::cmp::Reverse(Variant::OneToTen.category_order(o.rank)));
+    for o in odds {
+        let label = match o.rank {
+            HandRank::OnePair => "더블",
+            HandRank::HighCard => "족보 없음",
+            _ => o.rank.name(),
+        };
+        let claim = claims.iter().find(|(rank, _)| *rank == o.rank).map_or(&[][..], |(_, c)| c);
+        row(label, o.probability, claim);
+    }
+
+    // README2는 숫자 조합(다중집합)을 같은 확률로 세었다. 실제 카드는 숫자마다 4장이라 가중치가 다르다
+    println!("\n[README2 방식: 숫자만 본 경우의 수 (확률 아님)]");
+    println!("  2장 숫자 조합 (중복 조합 10H2)      {}", multichoose(10, 2));
+    println!("  3장 서로 다른 숫자 (10C3)           {}", combinations(10, 3));
+    println!("  3장 숫자 조합 (중복 조합 10H3)      {}", multichoose(10, 3));
+
+    println!("\n[공식 예제]");
+    println!("  10P2                                {}", permutations(10, 2));
+    println!("  C(3+2-1, 2)                         {}", multichoose(3, 2));
+    println!("  {{1,1,1,2,3}} 나열 5!/3!             {}", multiset_permutations(&[3, 1, 1]));
+    println!("  C(40, 2)                            {}", combinations(40, 2));
+
+    if !poker {
+        return;
+    }
+
+    let full = Deck::new().cards;
+    println!("\n== 52장 포커 ==");
+    println!("\n[2장 홀카드]");
+    row("포켓 페어", event_probability(&full, 2, same_rank), &[]);
+    row("수딧", event_probability(&full, 2, same_suit), &[]);
+
+    println!("\n[5장]");
+    print_category_table(Variant::Holdem, &full, 5, |hand| {
+        evaluate_strength(hand).unwrap().rank()
+    });
+
+    let short = Deck::from_spec(&DeckSpec::short()).cards;
+    println!("\n== 숏덱: {}장 (2~5 제외) ==", short.len());
+    println!("\n[5장]");
+    print_category_table(Variant::ShortDeck, &short, 5, |hand| {
+        evaluate_short_deck_hand(hand).unwrap().rank
+    });
+}
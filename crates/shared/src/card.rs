@@ -1,5 +1,9 @@
 use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 /// 카드 슈트 (무늬)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash)]
@@ -11,6 +15,40 @@ pub enum Suit {
 }
 
 impl Suit {
+    /// 덱을 만들 때의 순서
+    pub const ALL: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
+
+    /// 0..4 인덱스 (`ALL`의 순서)
+    pub fn index(&self) -> u8 {
+        match self {
+            Suit::Hearts => 0,
+            Suit::Diamonds => 1,
+            Suit::Clubs => 2,
+            Suit::Spades => 3,
+        }
+    }
+
+    /// ASCII 코드 (h, d, c, s)
+    pub fn code(&self) -> char {
+        match self {
+            Suit::Hearts => 'h',
+            Suit::Diamonds => 'd',
+            Suit::Clubs => 'c',
+            Suit::Spades => 's',
+        }
+    }
+
+    /// ASCII 코드 또는 무늬 기호 한 글자
+    pub fn from_char(c: char) -> Option<Suit> {
+        match c {
+            'h' | 'H' | '♥' | '♡' => Some(Suit::Hearts),
+            'd' | 'D' | '♦' | '♢' => Some(Suit::Diamonds),
+            'c' | 'C' | '♣' | '♧' => Some(Suit::Clubs),
+            's' | 'S' | '♠' | '♤' => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+
     pub fn symbol(&self) -> &str {
         match self {
             Suit::Hearts => "♥",
@@ -40,6 +78,49 @@ pub enum Rank {
 }
 
 impl Rank {
+    /// 낮은 랭크부터
+    pub const ALL: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+
+    /// 숫자 값(2~14)으로 랭크를 찾는다.
+    pub fn from_value(value: u8) -> Option<Rank> {
+        Rank::ALL.iter().copied().find(|r| *r as u8 == value)
+    }
+
+    /// ASCII 코드 (2~9, T, J, Q, K, A)
+    pub fn code(&self) -> char {
+        match self {
+            Rank::Ten => 'T',
+            _ => self.symbol().chars().next().unwrap(),
+        }
+    }
+
+    /// ASCII 코드 한 글자 (대소문자 무시)
+    pub fn from_char(c: char) -> Option<Rank> {
+        match c.to_ascii_uppercase() {
+            '2'..='9' => Rank::from_value(c as u8 - b'0'),
+            'T' => Some(Rank::Ten),
+            'J' => Some(Rank::Jack),
+            'Q' => Some(Rank::Queen),
+            'K' => Some(Rank::King),
+            'A' => Some(Rank::Ace),
+            _ => None,
+        }
+    }
+
     pub fn symbol(&self) -> &str {
         match self {
             Rank::Two => "2",
@@ -71,51 +152,294 @@ impl Card {
         Self { rank, suit }
     }
 
-    pub fn to_string(&self) -> String {
-        format!("{}{}", self.rank.symbol(), self.suit.symbol())
+    /// 0..52 압축 인덱스 (랭크 순, 같은 랭크 안에서는 슈트 순). 2♥ = 0, A♠ = 51
+    pub fn index(&self) -> u8 {
+        (self.rank as u8 - Rank::Two as u8) * 4 + self.suit.index()
+    }
+
+    pub fn from_index(index: u8) -> Option<Card> {
+        if index >= 52 {
+            return None;
+        }
+        let rank = Rank::from_value(index / 4 + Rank::Two as u8)?;
+        Some(Card::new(rank, Suit::ALL[(index % 4) as usize]))
+    }
+
+    /// ASCII 코드 ("Th", "As")
+    pub fn code(&self) -> String {
+        format!("{}{}", self.rank.code(), self.suit.code())
+    }
+}
+
+/// 카드 문자열을 해석할 수 없음
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseCardError {
+    pub input: String,
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "카드로 해석할 수 없습니다: {}", self.input)
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+/// 기호 형식 ("10♥"). `{:#}`로 출력하면 ASCII 형식 ("Th")
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.code())
+        } else {
+            f.write_str(self.symbol())
+        }
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.code())
+        } else {
+            f.write_str(self.symbol())
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#}{:#}", self.rank, self.suit)
+        } else {
+            write!(f, "{}{}", self.rank, self.suit)
+        }
+    }
+}
+
+/// "T", "10", "t" 모두 허용
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseCardError {
+            input: s.to_string(),
+        };
+        if s == "10" {
+            return Ok(Rank::Ten);
+        }
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Rank::from_char(c).ok_or_else(error),
+            _ => Err(error()),
+        }
+    }
+}
+
+/// "h", "♥" 모두 허용
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Suit::from_char(c),
+            _ => None,
+        }
+        .ok_or_else(|| ParseCardError {
+            input: s.to_string(),
+        })
+    }
+}
+
+/// "Th", "As", "10♥", "A♠" 모두 허용
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = parse_cards(s)?;
+        match cards.as_slice() {
+            [card] => Ok(*card),
+            _ => Err(ParseCardError {
+                input: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// 여러 장을 이어 쓴 문자열을 해석한다 ("AhKd7c", "A♥ K♦ 7♣", "Ah,Kd,7c").
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, ParseCardError> {
+    let error = || ParseCardError {
+        input: s.to_string(),
+    };
+    let chars: Vec<char> = s
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',')
+        .collect();
+
+    let mut cards = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let (rank, len) = if chars[i] == '1' && chars.get(i + 1) == Some(&'0') {
+            (Rank::Ten, 2)
+        } else {
+            (Rank::from_char(chars[i]).ok_or_else(error)?, 1)
+        };
+        let suit = chars
+            .get(i + len)
+            .and_then(|c| Suit::from_char(*c))
+            .ok_or_else(error)?;
+        cards.push(Card::new(rank, suit));
+        i += len + 1;
+    }
+    Ok(cards)
+}
+
+/// 카드 집합 비트마스크 (bit = `Card::index`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CardSet(pub u64);
+
+impl CardSet {
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    pub fn insert(&mut self, card: Card) {
+        self.0 |= 1 << card.index();
+    }
+
+    pub fn remove(&mut self, card: Card) {
+        self.0 &= !(1 << card.index());
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & (1 << card.index()) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// 인덱스 순서대로
+    pub fn iter(&self) -> impl Iterator<Item = Card> + '_ {
+        (0..52u8)
+            .filter(|i| self.0 & (1 << i) != 0)
+            .filter_map(Card::from_index)
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut set = CardSet::new();
+        for card in iter {
+            set.insert(card);
+        }
+        set
     }
 }
 
 /// 덱
+#[derive(Debug, Clone)]
 pub struct Deck {
     pub cards: Vec<Card>,
 }
 
+/// 덱 구성: 어떤 랭크와 무늬로 덱을 만드는지
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckSpec {
+    pub ranks: Vec<Rank>,
+    pub suits: Vec<Suit>,
+}
+
+impl DeckSpec {
+    /// 52장
+    pub fn standard() -> Self {
+        Self {
+            ranks: Rank::ALL.to_vec(),
+            suits: Suit::ALL.to_vec(),
+        }
+    }
+
+    /// 숏덱 (6+): 2~5를 뺀 36장
+    pub fn short() -> Self {
+        Self::standard().without_ranks(&[Rank::Two, Rank::Three, Rank::Four, Rank::Five])
+    }
+
+    /// 1~10 하우스 게임: 에이스(1)~10, 40장
+    pub fn one_to_ten() -> Self {
+        Self::standard().without_ranks(&[Rank::Jack, Rank::Queen, Rank::King])
+    }
+
+    pub fn without_ranks(mut self, ranks: &[Rank]) -> Self {
+        self.ranks.retain(|r| !ranks.contains(r));
+        self
+    }
+
+    pub fn contains(&self, card: &Card) -> bool {
+        self.ranks.contains(&card.rank) && self.suits.contains(&card.suit)
+    }
+
+    /// 덱 장수
+    pub fn len(&self) -> usize {
+        self.ranks.len() * self.suits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for DeckSpec {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
 impl Deck {
+    /// 섞지 않은 52장
     pub fn new() -> Self {
-        let mut cards = Vec::new();
-        let suits = [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
-        let ranks = [
-            Rank::Two,
-            Rank::Three,
-            Rank::Four,
-            Rank::Five,
-            Rank::Six,
-            Rank::Seven,
-            Rank::Eight,
-            Rank::Nine,
-            Rank::Ten,
-            Rank::Jack,
-            Rank::Queen,
-            Rank::King,
-            Rank::Ace,
-        ];
-
-        for suit in &suits {
-            for rank in &ranks {
-                cards.push(Card::new(*rank, *suit));
+        Self::from_spec(&DeckSpec::standard())
+    }
+
+    /// 덱 구성대로 섞지 않은 덱을 만든다. 무늬 순서대로, 무늬 안에서는 낮은 랭크부터
+    pub fn from_spec(spec: &DeckSpec) -> Self {
+        let mut cards = Vec::with_capacity(spec.len());
+        for &suit in &spec.suits {
+            for &rank in &spec.ranks {
+                cards.push(Card::new(rank, suit));
             }
         }
 
         Self { cards }
     }
 
+    /// 암호학적으로 안전한 OS 시드 RNG로 섞는다.
     pub fn shuffle(&mut self) {
         let mut rng = rand::thread_rng();
-        self.cards.shuffle(&mut rng);
+        self.shuffle_with(&mut rng);
+    }
+
+    /// 주어진 RNG로 섞는다. 테스트나 시뮬레이션에서 결과를 고정할 때 사용.
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    /// 시드로 섞는다. 같은 시드는 항상 같은 덱 순서를 만든다.
+    pub fn shuffle_seeded(&mut self, seed: u64) {
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        self.shuffle_with(&mut rng);
     }
 
     pub fn deal(&mut self) -> Option<Card> {
         self.cards.pop()
     }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
 }
\ No newline at end of file
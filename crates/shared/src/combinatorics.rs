@@ -0,0 +1,174 @@
+use crate::card::Card;
+use crate::hand::HandRank;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 조합 nCk. 중간 계산은 u128로 해서 결과가 u64에 들어가면 정확하다 (넘치면 `u64::MAX`).
+pub fn combinations(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut acc: u128 = 1;
+    for i in 0..k as u128 {
+        // acc * (n - i)는 항상 (i + 1)로 나누어떨어진다
+        acc = acc * (n as u128 - i) / (i + 1);
+        if acc > u64::MAX as u128 {
+            return u64::MAX;
+        }
+    }
+    acc as u64
+}
+
+/// 순열 nPk = n! / (n-k)!. 넘치면 `u64::MAX`
+pub fn permutations(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    (n - k + 1..=n).fold(1u64, |acc, x| acc.saturating_mul(x))
+}
+
+/// 중복 조합 nHk = C(n+k-1, k): 순서 무시, 중복 허용
+pub fn multichoose(n: u64, k: u64) -> u64 {
+    if n == 0 {
+        return if k == 0 { 1 } else { 0 };
+    }
+    combinations(n + k - 1, k)
+}
+
+/// 중복 순열 n^k: 순서 고려, 중복 허용. 넘치면 `u64::MAX`
+pub fn repeated_permutations(n: u64, k: u32) -> u64 {
+    n.saturating_pow(k)
+}
+
+/// 같은 원소가 섞인 나열의 수 (다중집합 순열).
+/// `counts`는 원소별 개수이고 결과는 (합)! / (각 개수!의 곱)이다. 예: {1,1,1,2,3} → [3,1,1] → 20
+pub fn multiset_permutations(counts: &[u64]) -> u64 {
+    // 다항계수를 이항계수의 곱으로 계산한다
+    let mut total = 0;
+    let mut acc: u64 = 1;
+    for &count in counts {
+        total += count;
+        acc = acc.saturating_mul(combinations(total, count));
+    }
+    acc
+}
+
+/// 사전순 다음 조합. 마지막이면 false.
+pub fn next_combination(idx: &mut [usize], n: usize) -> bool {
+    let k = idx.len();
+    for i in (0..k).rev() {
+        if idx[i] < n - k + i {
+            idx[i] += 1;
+            for j in i + 1..k {
+                idx[j] = idx[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+/// `deck`에서 `k`장을 고르는 모든 조합을 돈다.
+pub fn for_each_combination(deck: &[Card], k: usize, mut visit: impl FnMut(&[Card])) {
+    if k > deck.len() {
+        return;
+    }
+    let mut idx: Vec<usize> = (0..k).collect();
+    let mut hand = Vec::with_capacity(k);
+    loop {
+        hand.clear();
+        hand.extend(idx.iter().map(|&i| deck[i]));
+        visit(&hand);
+        if !next_combination(&mut idx, deck.len()) {
+            break;
+        }
+    }
+}
+
+/// 정확한 확률 (경우의 수 / 전체 경우의 수)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Probability {
+    pub hits: u64,
+    pub total: u64,
+}
+
+impl Probability {
+    pub fn new(hits: u64, total: u64) -> Self {
+        Self { hits, total }
+    }
+
+    /// 기약분수 (분자, 분모)
+    pub fn reduced(&self) -> (u64, u64) {
+        if self.total == 0 {
+            return (0, 1);
+        }
+        let divisor = gcd(self.hits, self.total);
+        (self.hits / divisor, self.total / divisor)
+    }
+
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / self.total as f64 * 100.0
+    }
+}
+
+/// "1/13 (7.69%)"
+impl fmt::Display for Probability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (hits, total) = self.reduced();
+        write!(f, "{}/{} ({:.2}%)", hits, total, self.percent())
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a.max(1) } else { gcd(b, a % b) }
+}
+
+/// 족보별 경우의 수
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryOdds {
+    pub rank: HandRank,
+    pub probability: Probability,
+}
+
+/// `deck`에서 `k`장을 뽑는 모든 경우를 `classify`로 분류해 족보별 확률을 센다.
+/// 결과는 나온 족보만, 높은 족보부터 정렬한다.
+pub fn category_odds(
+    deck: &[Card],
+    k: usize,
+    mut classify: impl FnMut(&[Card]) -> HandRank,
+) -> Vec<CategoryOdds> {
+    let mut counts = [0u64; HandRank::RoyalFlush as usize + 1];
+    let mut total = 0;
+    for_each_combination(deck, k, |hand| {
+        counts[classify(hand) as usize] += 1;
+        total += 1;
+    });
+
+    let mut odds: Vec<CategoryOdds> = HandRank::ALL
+        .iter()
+        .filter(|rank| counts[**rank as usize] > 0)
+        .map(|&rank| CategoryOdds {
+            rank,
+            probability: Probability::new(counts[rank as usize], total),
+        })
+        .collect();
+    odds.reverse();
+    odds
+}
+
+/// `deck`에서 `k`장을 뽑았을 때 `event`가 참일 확률
+pub fn event_probability(deck: &[Card], k: usize, mut event: impl FnMut(&[Card]) -> bool) -> Probability {
+    let mut hits = 0;
+    let mut total = 0;
+    for_each_combination(deck, k, |hand| {
+        if event(hand) {
+            hits += 1;
+        }
+        total += 1;
+    });
+    Probability::new(hits, total)
+}
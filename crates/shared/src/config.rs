@@ -0,0 +1,125 @@
+use crate::variant::Variant;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 앤티 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Ante {
+    /// 앤티 없음
+    #[default]
+    None,
+    /// 핸드에 참가하는 모든 플레이어가 같은 금액을 낸다
+    Everyone(i32),
+    /// 빅 블라인드가 테이블 전체 앤티를 한 번에 낸다
+    BigBlind(i32),
+}
+
+/// 테이블 설정. 테이블을 만들 때 정하고 핸드 사이에만 바꿀 수 있다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableConfig {
+    pub variant: Variant,
+    pub small_blind: i32,
+    pub big_blind: i32,
+    pub ante: Ante,
+    /// 바이인을 정하지 않고 앉을 때의 스택
+    pub starting_stack: i32,
+    pub min_buy_in: i32,
+    pub max_buy_in: i32,
+    /// 좌석 수 (게임 종류의 최대 인원을 넘을 수 없다)
+    pub seats: usize,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            variant: Variant::Holdem,
+            small_blind: 5,
+            big_blind: 10,
+            ante: Ante::None,
+            starting_stack: 1000,
+            min_buy_in: 200,
+            max_buy_in: 2000,
+            seats: 10,
+        }
+    }
+}
+
+/// 설정이 잘못된 이유
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConfigError {
+    /// 블라인드가 0 이하이거나 스몰 블라인드가 빅 블라인드보다 큼
+    InvalidBlinds,
+    /// 앤티 금액이 0 이하
+    InvalidAnte,
+    /// 최소 바이인이 0 이하이거나 최대 바이인보다 큼
+    InvalidBuyInRange,
+    /// 시작 스택이 바이인 범위를 벗어남
+    StartingStackOutOfRange,
+    /// 좌석 수가 2 미만이거나 게임 종류의 최대 인원을 넘음
+    InvalidSeats { max: usize },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidBlinds => {
+                f.write_str("블라인드는 0보다 커야 하고 스몰 블라인드가 빅 블라인드보다 클 수 없습니다")
+            }
+            ConfigError::InvalidAnte => f.write_str("앤티는 0보다 커야 합니다"),
+            ConfigError::InvalidBuyInRange => f.write_str("바이인 범위가 잘못되었습니다"),
+            ConfigError::StartingStackOutOfRange => {
+                f.write_str("시작 스택은 바이인 범위 안이어야 합니다")
+            }
+            ConfigError::InvalidSeats { max } => write!(f, "좌석 수는 2~{}석이어야 합니다", max),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl TableConfig {
+    /// 기본 설정에 게임 종류만 바꾼다. 좌석 수는 게임 종류의 최대 인원에 맞춘다.
+    pub fn for_variant(variant: Variant) -> Self {
+        Self {
+            variant,
+            seats: variant.max_players(),
+            ..Self::default()
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.small_blind <= 0 || self.big_blind <= 0 || self.small_blind > self.big_blind {
+            return Err(ConfigError::InvalidBlinds);
+        }
+        if let Ante::Everyone(amount) | Ante::BigBlind(amount) = self.ante
+            && amount <= 0
+        {
+            return Err(ConfigError::InvalidAnte);
+        }
+        if self.min_buy_in <= 0 || self.min_buy_in > self.max_buy_in {
+            return Err(ConfigError::InvalidBuyInRange);
+        }
+        if !self.buy_in_allowed(self.starting_stack) {
+            return Err(ConfigError::StartingStackOutOfRange);
+        }
+        let max = self.variant.max_players();
+        if self.seats < 2 || self.seats > max {
+            return Err(ConfigError::InvalidSeats { max });
+        }
+        Ok(())
+    }
+
+    pub fn buy_in_allowed(&self, amount: i32) -> bool {
+        (self.min_buy_in..=self.max_buy_in).contains(&amount)
+    }
+
+    /// "5/10", 앤티가 있으면 "5/10 (앤티 1)" / "5/10 (BB 앤티 10)"
+    pub fn stakes(&self) -> String {
+        let blinds = format!("{}/{}", self.small_blind, self.big_blind);
+        match self.ante {
+            Ante::None => blinds,
+            Ante::Everyone(amount) => format!("{} (앤티 {})", blinds, amount),
+            Ante::BigBlind(amount) => format!("{} (BB 앤티 {})", blinds, amount),
+        }
+    }
+}
@@ -0,0 +1,740 @@
+use crate::card::{Card, Deck};
+use crate::config::{Ante, ConfigError, TableConfig};
+use crate::game::{build_pots, GamePhase, GameState, Payout, Player, Pot};
+use crate::hand::HandValue;
+use crate::protocol::ClientMessage;
+use crate::variant::{LowHand, Variant};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// 플레이어 베팅 액션 (`ClientMessage`의 베팅 부분)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Raise { amount: i32 },
+}
+
+impl Action {
+    /// 베팅 액션이 아닌 메시지(Join, Ready 등)는 `None`
+    pub fn from_message(message: &ClientMessage) -> Option<Self> {
+        match message {
+            ClientMessage::Fold => Some(Action::Fold),
+            ClientMessage::Check => Some(Action::Check),
+            ClientMessage::Call => Some(Action::Call),
+            ClientMessage::Raise { amount } => Some(Action::Raise { amount: *amount }),
+            _ => None,
+        }
+    }
+
+    /// 액션 알림용 문구
+    pub fn describe(&self, paid: i32, all_in: bool) -> String {
+        match self {
+            Action::Fold => "폴드".to_string(),
+            Action::Check => "체크".to_string(),
+            _ if all_in => format!("올인 {}", paid),
+            Action::Call => "콜".to_string(),
+            Action::Raise { amount } => format!("레이즈 {}", amount),
+        }
+    }
+}
+
+/// 테이블 상태가 바뀌면서 생긴 일. 서버는 이것을 메시지로 바꿔 전송한다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TableEvent {
+    /// 새 핸드 시작
+    HandStarted { dealer_idx: usize },
+
+    /// 앤티
+    AntePosted { player_id: String, amount: i32 },
+
+    /// 블라인드 베팅
+    BlindPosted { player_id: String, amount: i32 },
+
+    /// 홀카드 배분 (해당 플레이어에게만 공개)
+    HoleCardsDealt { player_id: String, cards: Vec<Card> },
+
+    /// 플레이어 액션
+    PlayerActed {
+        player_id: String,
+        action: Action,
+        paid: i32,
+        all_in: bool,
+    },
+
+    /// 다음 스트리트로 진행
+    PhaseChanged {
+        phase: GamePhase,
+        community_cards: Vec<Card>,
+    },
+
+    /// 핸드 종료 및 지급. 쇼다운까지 갔으면 공개된 핸드도 함께
+    HandEnded {
+        winners: Vec<Payout>,
+        showdown: Vec<ShowdownHand>,
+    },
+}
+
+/// 쇼다운에서 공개된 핸드 하나
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShowdownHand {
+    pub player_id: String,
+    /// 최고 5장
+    pub best: Vec<Card>,
+    pub value: HandValue,
+    /// `best` 중 승부를 가른 카드 (`HandValue::deciding_cards`)
+    pub deciding: Vec<Card>,
+    /// 하이-로우 게임에서 자격을 갖춘 로우 핸드
+    pub low: Option<LowHand>,
+}
+
+/// 덱에서 나간 카드의 행선지
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DealTarget {
+    Player(String),
+    Burn,
+    Board,
+}
+
+/// 덱에서 나간 카드 한 장의 기록
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealtCard {
+    pub card: Card,
+    pub target: DealTarget,
+}
+
+/// 테이블이 받아들일 수 없는 명령
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TableError {
+    /// 플레이어가 2명 미만
+    NotEnoughPlayers,
+    /// 이미 핸드가 진행 중
+    HandInProgress,
+    /// 진행 중인 핸드가 없음
+    NoHandInProgress,
+    /// 테이블에 없는 플레이어
+    UnknownPlayer,
+    /// 차례가 아닌 플레이어
+    NotYourTurn,
+    /// 맞춰야 할 베팅이 있어서 체크 불가
+    CannotCheck,
+    /// 레이즈 금액이 0 이하
+    InvalidRaiseAmount,
+    /// 올인이 아닌데 최소 레이즈보다 작음
+    RaiseTooSmall { min_raise: i32 },
+    /// 숏 올인 레이즈로는 이미 액션한 플레이어에게 레이즈 기회가 다시 열리지 않음
+    RaiseNotAllowed,
+    /// 좌석 수를 넘음
+    TableFull { max_players: usize },
+    /// 이미 이 테이블에 앉아 있음
+    AlreadySeated,
+    /// 바이인 금액이 테이블 범위를 벗어남
+    InvalidBuyIn { min: i32, max: i32 },
+    /// 테이블 설정이 잘못됨
+    InvalidConfig(ConfigError),
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::NotEnoughPlayers => f.write_str("플레이어가 2명 이상 필요합니다"),
+            TableError::HandInProgress => f.write_str("이미 게임이 진행 중입니다"),
+            TableError::NoHandInProgress => f.write_str("진행 중인 게임이 없습니다"),
+            TableError::UnknownPlayer => f.write_str("테이블에 없는 플레이어입니다"),
+            TableError::NotYourTurn => f.write_str("당신의 턴이 아닙니다"),
+            TableError::CannotCheck => f.write_str("체크할 수 없습니다"),
+            TableError::InvalidRaiseAmount => f.write_str("레이즈 금액은 0보다 커야 합니다"),
+            TableError::RaiseTooSmall { min_raise } => {
+                write!(f, "최소 레이즈 금액은 {}입니다", min_raise)
+            }
+            TableError::RaiseNotAllowed => f.write_str("콜 또는 폴드만 할 수 있습니다"),
+            TableError::TableFull { max_players } => {
+                write!(f, "테이블이 가득 찼습니다 (최대 {}명)", max_players)
+            }
+            TableError::AlreadySeated => f.write_str("이미 테이블에 앉아 있습니다"),
+            TableError::InvalidBuyIn { min, max } => {
+                write!(f, "바이인은 {}~{} 사이여야 합니다", min, max)
+            }
+            TableError::InvalidConfig(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for TableError {}
+
+/// 네트워크와 무관한 핸드 진행 상태 머신.
+/// 같은 덱과 같은 액션을 넣으면 항상 같은 상태와 이벤트가 나온다.
+#[derive(Debug, Clone)]
+pub struct Table {
+    state: GameState,
+    deck: Deck,
+    deal_log: Vec<DealtCard>,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self {
+            state: GameState::new(),
+            deck: Deck { cards: Vec::new() },
+            deal_log: Vec::new(),
+        }
+    }
+
+    /// 정해진 게임 종류와 기본 설정으로 빈 테이블을 만든다.
+    pub fn with_variant(variant: Variant) -> Self {
+        Self::with_config(TableConfig::for_variant(variant))
+            .expect("게임 종류별 기본 설정은 항상 유효하다")
+    }
+
+    /// 설정을 검사하고 빈 테이블을 만든다.
+    pub fn with_config(config: TableConfig) -> Result<Self, TableError> {
+        config.validate().map_err(TableError::InvalidConfig)?;
+        let mut table = Self::new();
+        table.state.config = config;
+        Ok(table)
+    }
+
+    pub fn config(&self) -> &TableConfig {
+        &self.state.config
+    }
+
+    pub fn variant(&self) -> Variant {
+        self.state.config.variant
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    /// 이번 핸드에서 덱에서 나간 카드 순서 (번 카드 포함).
+    /// 같은 덱 순서로 다시 진행하면 똑같이 재현된다.
+    pub fn deal_log(&self) -> &[DealtCard] {
+        &self.deal_log
+    }
+
+    /// 빈 자리에 앉힌다. 스택은 바이인 범위 안이어야 한다.
+    /// 핸드 중에 앉으면 홀카드가 없으니 다음 핸드까지 폴드한 것으로 둔다.
+    pub fn seat_player(&mut self, mut player: Player) -> Result<(), TableError> {
+        let config = &self.state.config;
+        if self.state.players.iter().any(|p| p.id == player.id) {
+            return Err(TableError::AlreadySeated);
+        }
+        if self.state.players.len() >= config.seats {
+            return Err(TableError::TableFull {
+                max_players: config.seats,
+            });
+        }
+        if !config.buy_in_allowed(player.chips) {
+            return Err(TableError::InvalidBuyIn {
+                min: config.min_buy_in,
+                max: config.max_buy_in,
+            });
+        }
+        if self.state.is_betting() {
+            player.hand.clear();
+            player.folded = true;
+        }
+        self.state.players.push(player);
+        Ok(())
+    }
+
+    /// 핸드 사이에 설정을 바꾼다. 이미 앉은 플레이어 수보다 좌석을 줄일 수는 없다.
+    pub fn set_config(&mut self, config: TableConfig) -> Result<(), TableError> {
+        if self.state.is_betting() {
+            return Err(TableError::HandInProgress);
+        }
+        config.validate().map_err(TableError::InvalidConfig)?;
+        if self.state.players.len() > config.seats {
+            return Err(TableError::TableFull {
+                max_players: config.seats,
+            });
+        }
+        self.state.config = config;
+        Ok(())
+    }
+
+    /// 핸드 사이에 게임 종류만 바꾼다. 좌석을 꽉 채워 두었으면 새 게임의 최대 인원을 따르고,
+    /// 아니면 새 최대 인원을 넘지 않게만 줄인다.
+    pub fn set_variant(&mut self, variant: Variant) -> Result<(), TableError> {
+        let current = &self.state.config;
+        let seats = if current.seats == current.variant.max_players() {
+            variant.max_players()
+        } else {
+            current.seats.min(variant.max_players())
+        };
+        let config = TableConfig {
+            variant,
+            seats,
+            ..current.clone()
+        };
+        self.set_config(config)
+    }
+
+    /// 자리에서 일으킨다. 핸드 중에는 좌석 순서가 바뀌면 안 되므로 핸드 사이에만 가능하다.
+    pub fn remove_player(&mut self, player_id: &str) -> Result<(), TableError> {
+        if self.state.is_betting() {
+            return Err(TableError::HandInProgress);
+        }
+        self.state.players.retain(|p| p.id != player_id);
+        Ok(())
+    }
+
+    /// 연결 상태를 표시한다. 없는 플레이어면 false
+    pub fn set_connected(&mut self, player_id: &str, connected: bool) -> bool {
+        match self.state.players.iter_mut().find(|p| p.id == player_id) {
+            Some(player) => {
+                player.is_active = connected;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 연결이 끊긴 플레이어 대신 할 액션. 차례가 아니면 `None`
+    /// 맞출 베팅이 없으면 체크, 있으면 폴드한다.
+    pub fn auto_action(&self, player_id: &str) -> Option<Action> {
+        let state = &self.state;
+        if !state.is_betting() {
+            return None;
+        }
+        let player = state.players.get(state.current_player_idx)?;
+        if player.id != player_id {
+            return None;
+        }
+        Some(if player.bet >= state.current_bet {
+            Action::Check
+        } else {
+            Action::Fold
+        })
+    }
+
+    /// 섞인 덱으로 새 핸드를 시작한다. 홀카드와 블라인드까지 처리한다.
+    /// 게임 종류에서 쓰지 않는 카드는 순서를 유지한 채 덱에서 빠진다 (`Variant::deck_from`).
+    pub fn start_hand(&mut self, deck: Deck) -> Result<Vec<TableEvent>, TableError> {
+        // 칩이 없는 플레이어는 앉아 있어도 세지 않는다
+        if self.state.funded_players() < 2 {
+            return Err(TableError::NotEnoughPlayers);
+        }
+        if self.state.is_betting() {
+            return Err(TableError::HandInProgress);
+        }
+
+        // 플레이어가 나갔거나 칩이 떨어졌으면 버튼을 칩이 남은 다음 자리로 옮긴다
+        let len = self.state.players.len();
+        let dealer = self.state.dealer_idx % len;
+        self.state.dealer_idx = if self.state.players[dealer].chips > 0 {
+            dealer
+        } else {
+            self.state.next_funded_after(dealer).unwrap_or(dealer)
+        };
+
+        let mut events = vec![TableEvent::HandStarted {
+            dealer_idx: self.state.dealer_idx,
+        }];
+
+        for player in &mut self.state.players {
+            player.hand.clear();
+            // 칩이 없는 플레이어는 이번 핸드에서 제외
+            player.folded = player.chips <= 0;
+            player.all_in = false;
+            player.show_cards = false;
+            player.acted = false;
+            player.bet = 0;
+            player.total_bet = 0;
+            player.ante = 0;
+        }
+
+        // 이번 핸드는 이 덱 하나로 끝까지 진행한다.
+        // 딜러 왼쪽부터 한 장씩 (홀카드 장수만큼) 돌며 홀카드를 배분. 칩이 없어 빠진 자리는 건너뛴다
+        let variant = self.state.config.variant;
+        self.deck = variant.deck_from(deck);
+        self.deal_log.clear();
+        for _ in 0..variant.hole_cards() {
+            for offset in 1..=len {
+                let idx = (self.state.dealer_idx + offset) % len;
+                if self.state.players[idx].folded {
+                    continue;
+                }
+                let player_id = self.state.players[idx].id.clone();
+                let card = self.draw(DealTarget::Player(player_id));
+                self.state.players[idx].hand.push(card);
+            }
+        }
+
+        // 커뮤니티 카드 초기화 (첫 베팅 전에 여는 보드가 있으면 바로 연다)
+        self.state.community_cards.clear();
+        for _ in 0..variant.opening_board_cards() {
+            let card = self.draw(DealTarget::Board);
+            self.state.community_cards.push(card);
+        }
+
+        let state = &mut self.state;
+        let config = state.config.clone();
+        state.pot = 0;
+        state.current_bet = config.big_blind;
+        state.min_raise = config.big_blind;
+        state.phase = GamePhase::PreFlop;
+
+        let (small_blind_idx, big_blind_idx) = state.blind_positions();
+
+        // 앤티는 블라인드보다 먼저 낸다
+        let ante_payers: Vec<(usize, i32)> = match config.ante {
+            Ante::None => Vec::new(),
+            Ante::Everyone(amount) => (0..state.players.len())
+                .filter(|&idx| !state.players[idx].folded)
+                .map(|idx| (idx, amount))
+                .collect(),
+            Ante::BigBlind(amount) => vec![(big_blind_idx, amount)],
+        };
+        for (idx, ante) in ante_payers {
+            let amount = state.players[idx].post_ante(ante);
+            state.pot += amount;
+            events.push(TableEvent::AntePosted {
+                player_id: state.players[idx].id.clone(),
+                amount,
+            });
+        }
+
+        // 블라인드 배팅 (스택이 모자라면 올인)
+        for (idx, blind) in [
+            (small_blind_idx, config.small_blind),
+            (big_blind_idx, config.big_blind),
+        ] {
+            let amount = state.players[idx].commit_chips(blind);
+            state.pot += amount;
+            events.push(TableEvent::BlindPosted {
+                player_id: state.players[idx].id.clone(),
+                amount,
+            });
+        }
+
+        state.current_player_idx = state
+            .next_to_act_after(big_blind_idx)
+            .unwrap_or(small_blind_idx);
+
+        for player in state.players.iter().filter(|p| !p.hand.is_empty()) {
+            events.push(TableEvent::HoleCardsDealt {
+                player_id: player.id.clone(),
+                cards: player.hand.clone(),
+            });
+        }
+
+        // 블라인드만으로 모두 올인이면 바로 보드를 연다
+        if self.state.betting_round_complete() {
+            self.next_phase(&mut events);
+        }
+
+        Ok(events)
+    }
+
+    /// 현재 차례인 플레이어의 액션을 적용한다.
+    pub fn apply(&mut self, player_id: &str, action: Action) -> Result<Vec<TableEvent>, TableError> {
+        if !self.state.is_betting() {
+            return Err(TableError::NoHandInProgress);
+        }
+
+        let state = &mut self.state;
+        let player_idx = state
+            .players
+            .iter()
+            .position(|p| p.id == player_id)
+            .ok_or(TableError::UnknownPlayer)?;
+
+        if player_idx != state.current_player_idx {
+            return Err(TableError::NotYourTurn);
+        }
+
+        let paid = match action {
+            Action::Fold => {
+                state.players[player_idx].folded = true;
+                0
+            }
+            Action::Check => {
+                if state.players[player_idx].bet < state.current_bet {
+                    return Err(TableError::CannotCheck);
+                }
+                0
+            }
+            Action::Call => {
+                let call_amount = state.current_bet - state.players[player_idx].bet;
+                state.players[player_idx].commit_chips(call_amount)
+            }
+            Action::Raise { amount } => {
+                let player = &state.players[player_idx];
+                if amount <= 0 {
+                    return Err(TableError::InvalidRaiseAmount);
+                }
+                if player.acted {
+                    return Err(TableError::RaiseNotAllowed);
+                }
+                let stack = player.bet + player.chips;
+                // 스택보다 큰 레이즈는 올인으로 처리한다 (큰 금액에서 넘치지 않도록 먼저 자른다)
+                let total_bet = state.current_bet.saturating_add(amount).min(stack);
+                // 최소 레이즈보다 작은 레이즈는 올인일 때만 허용
+                if amount < state.min_raise && total_bet < stack {
+                    return Err(TableError::RaiseTooSmall {
+                        min_raise: state.min_raise,
+                    });
+                }
+
+                let raise_amount = total_bet - player.bet;
+                let paid = state.players[player_idx].commit_chips(raise_amount);
+                let raised_by = state.players[player_idx].bet - state.current_bet;
+                if raised_by >= state.min_raise {
+                    // 풀 레이즈는 다른 플레이어에게 액션을 다시 연다
+                    state.min_raise = raised_by;
+                    for player in &mut state.players {
+                        player.acted = false;
+                    }
+                }
+                state.current_bet = state.current_bet.max(state.players[player_idx].bet);
+                paid
+            }
+        };
+        state.pot += paid;
+        state.players[player_idx].acted = true;
+
+        let mut events = vec![TableEvent::PlayerActed {
+            player_id: player_id.to_string(),
+            action,
+            paid,
+            all_in: state.players[player_idx].all_in,
+        }];
+
+        // 베팅 라운드 종료 체크
+        if state.contenders() == 1 {
+            let winner_id = state.players.iter().find(|p| !p.folded).unwrap().id.clone();
+            let pot = state.pot;
+            events.push(self.end_hand(
+                vec![Payout {
+                    player_id: winner_id,
+                    amount: pot,
+                }],
+                Vec::new(),
+            ));
+            return Ok(events);
+        }
+
+        // 다음 플레이어로 이동 (폴드/올인, 이미 액션을 마친 플레이어는 건너뜀)
+        if let Some(next_idx) = state.next_to_act_after(player_idx) {
+            state.current_player_idx = next_idx;
+        }
+
+        if state.betting_round_complete() {
+            self.next_phase(&mut events);
+        }
+
+        Ok(events)
+    }
+
+    /// 다음 스트리트로 진행한다. 베팅할 수 있는 플레이어가 둘 미만이면 쇼다운까지 바로 진행한다.
+    fn next_phase(&mut self, events: &mut Vec<TableEvent>) {
+        const STREETS: [GamePhase; 4] = [
+            GamePhase::PreFlop,
+            GamePhase::Flop,
+            GamePhase::Turn,
+            GamePhase::River,
+        ];
+        let variant = self.state.config.variant;
+
+        loop {
+            let Some(street) = STREETS.iter().position(|p| *p == self.state.phase) else {
+                return;
+            };
+            // 마지막 베팅 라운드가 끝났으면 쇼다운
+            let Some(&count) = variant.streets().get(street) else {
+                let state = &mut self.state;
+                state.phase = GamePhase::Showdown;
+                for player in &mut state.players {
+                    player.show_cards = !player.folded;
+                }
+                let (winners, showdown) = self.showdown();
+                events.push(self.end_hand(winners, showdown));
+                return;
+            };
+            let next = STREETS[street + 1].clone();
+
+            // 번 카드 한 장을 버리고 보드를 연다
+            if variant.burns() {
+                self.draw(DealTarget::Burn);
+            }
+            for _ in 0..count {
+                let card = self.draw(DealTarget::Board);
+                self.state.community_cards.push(card);
+            }
+
+            let state = &mut self.state;
+            state.phase = next;
+
+            for player in &mut state.players {
+                player.bet = 0;
+                player.acted = false;
+            }
+            state.current_bet = 0;
+            state.min_raise = state.config.big_blind;
+            if let Some(first_idx) = state.next_actor_after(state.dealer_idx) {
+                state.current_player_idx = first_idx;
+            }
+
+            events.push(TableEvent::PhaseChanged {
+                phase: state.phase.clone(),
+                community_cards: state.community_cards.clone(),
+            });
+
+            let actors = state.players.iter().filter(|p| p.can_act()).count();
+            if actors >= 2 {
+                return;
+            }
+        }
+    }
+
+    /// 팟별 지급액 계산. 메인 팟부터 차례로, 자격이 있는 플레이어 중 최고 핸드에게 지급한다.
+    /// 동점이면 똑같이 나누고 남는 칩은 딜러 왼쪽 첫 자리부터 준다.
+    /// 게임 종류에 맞는 핸드를 만들 수 없는 자리(홀카드가 없는 등)는 쇼다운에서 빠진다.
+    fn showdown(&self) -> (Vec<Payout>, Vec<ShowdownHand>) {
+        let state = &self.state;
+        let variant = state.config.variant;
+
+        let best_hands: Vec<(String, Vec<Card>, HandValue)> = state
+            .players
+            .iter()
+            .filter(|p| !p.folded)
+            .filter_map(|player| {
+                let (best, value) = variant.best_hand(&player.hand, &state.community_cards).ok()?;
+                Some((player.id.clone(), best, value))
+            })
+            .collect();
+        let hand_values: HashMap<String, HandValue> = best_hands
+            .iter()
+            .map(|(id, _, value)| (id.clone(), value.clone()))
+            .collect();
+        let low_hands: HashMap<String, LowHand> = state
+            .players
+            .iter()
+            .filter(|p| !p.folded)
+            .filter_map(|p| {
+                let low = variant.best_low(&p.hand, &state.community_cards)?;
+                Some((p.id.clone(), low))
+            })
+            .collect();
+
+        // 각자 가장 강한 상대와 비교해 승부를 가른 카드를 표시한다
+        let showdown = best_hands
+            .iter()
+            .map(|(id, best, value)| {
+                let runner_up = best_hands
+                    .iter()
+                    .filter(|(other, _, _)| other != id)
+                    .map(|(_, _, v)| v)
+                    .max_by(|a, b| variant.compare_hands(a, b));
+                ShowdownHand {
+                    player_id: id.clone(),
+                    best: best.clone(),
+                    value: value.clone(),
+                    deciding: value.deciding_cards(best, runner_up),
+                    low: low_hands.get(id).cloned(),
+                }
+            })
+            .collect();
+
+        // 조건에 맞는 플레이어를 딜러 왼쪽부터 좌석 순으로
+        let in_seat_order = |is_winner: &dyn Fn(&Player) -> bool| -> Vec<String> {
+            let mut winners: Vec<(usize, String)> = state
+                .players
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| is_winner(p))
+                .map(|(idx, p)| (state.seat_order(idx), p.id.clone()))
+                .collect();
+            winners.sort();
+            winners.into_iter().map(|(_, id)| id).collect()
+        };
+
+        let mut payouts = Vec::new();
+        for pot in build_pots(&state.players) {
+            let best = pot
+                .eligible
+                .iter()
+                .filter_map(|id| hand_values.get(id))
+                .max_by(|a, b| variant.compare_hands(a, b));
+            let Some(best) = best else {
+                // 자격 있는 누구도 핸드를 만들 수 없으면 칩을 없애지 않고 나눈다
+                let eligible = in_seat_order(&|p| pot.eligible.contains(&p.id));
+                payouts.extend(pot.split(&eligible));
+                continue;
+            };
+            let high_winners = in_seat_order(&|p| {
+                pot.eligible.contains(&p.id) && hand_values.get(&p.id) == Some(best)
+            });
+
+            // 하이-로우: 자격 있는 로우가 있으면 팟을 반으로 나누고 남는 칩은 하이에게 준다
+            let best_low = pot
+                .eligible
+                .iter()
+                .filter_map(|id| low_hands.get(id))
+                .reduce(|a, b| if b.beats(a) { b } else { a });
+            let Some(best_low) = best_low else {
+                payouts.extend(pot.split(&high_winners));
+                continue;
+            };
+            let low_winners = in_seat_order(&|p| {
+                pot.eligible.contains(&p.id) && low_hands.get(&p.id) == Some(best_low)
+            });
+
+            let low_pot = Pot {
+                amount: pot.amount / 2,
+                eligible: pot.eligible.clone(),
+            };
+            let high_pot = Pot {
+                amount: pot.amount - low_pot.amount,
+                eligible: pot.eligible,
+            };
+            payouts.extend(high_pot.split(&high_winners));
+            payouts.extend(low_pot.split(&low_winners));
+        }
+
+        (payouts, showdown)
+    }
+
+    fn draw(&mut self, target: DealTarget) -> Card {
+        let card = self.deck.deal().expect("덱에 카드가 부족합니다");
+        self.deal_log.push(DealtCard { card, target });
+        card
+    }
+
+    fn end_hand(&mut self, payouts: Vec<Payout>, showdown: Vec<ShowdownHand>) -> TableEvent {
+        let state = &mut self.state;
+
+        // 여러 팟을 이긴 플레이어는 한 줄로 합친다
+        let mut winners: Vec<Payout> = Vec::new();
+        for payout in payouts {
+            match winners.iter_mut().find(|w| w.player_id == payout.player_id) {
+                Some(winner) => winner.amount += payout.amount,
+                None => winners.push(payout),
+            }
+        }
+
+        for payout in &winners {
+            if let Some(winner) = state.players.iter_mut().find(|p| p.id == payout.player_id) {
+                winner.chips += payout.amount;
+            }
+        }
+
+        state.pot = 0;
+        state.phase = GamePhase::Waiting;
+        // 버튼은 칩이 남은 다음 자리로 넘어간다
+        state.dealer_idx = state
+            .next_funded_after(state.dealer_idx)
+            .unwrap_or((state.dealer_idx + 1) % state.players.len());
+
+        TableEvent::HandEnded { winners, showdown }
+    }
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self::new()
+    }
+}
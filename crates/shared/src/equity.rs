@@ -0,0 +1,218 @@
+use crate::card::{Card, Deck, Rank, Suit};
+use crate::combinatorics::{combinations, next_combination};
+use crate::strength::{seven_card_strength, HandStrength};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 에퀴티 계산 옵션
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EquityOptions {
+    /// 남은 보드 경우의 수가 이 이하이면 전부 계산한다
+    pub exhaustive_limit: u64,
+    /// 몬테카를로로 돌릴 보드 수
+    pub samples: u32,
+    /// 몬테카를로 시드 (같은 시드면 같은 결과)
+    pub seed: u64,
+}
+
+impl Default for EquityOptions {
+    fn default() -> Self {
+        Self {
+            exhaustive_limit: 2_000_000,
+            samples: 200_000,
+            seed: 0,
+        }
+    }
+}
+
+/// 플레이어 한 명의 결과 (퍼센트, 0~100)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlayerEquity {
+    pub win: f64,
+    pub tie: f64,
+    pub lose: f64,
+    /// 비긴 팟을 나눠 가진 몫까지 합친 기대 지분
+    pub equity: f64,
+}
+
+/// 에퀴티 계산 결과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquityReport {
+    /// 입력한 핸드 순서대로
+    pub players: Vec<PlayerEquity>,
+    /// 계산한 보드 수
+    pub boards: u64,
+    /// 전부 계산했는지 (false면 몬테카를로)
+    pub exhaustive: bool,
+}
+
+/// 에퀴티를 계산할 수 없는 이유
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EquityError {
+    /// 핸드가 2개 미만
+    NotEnoughPlayers,
+    /// 홀카드가 2장이 아님 (몇 번째 핸드인지)
+    WrongHoleCards { player: usize },
+    /// 보드가 5장 초과
+    TooManyBoardCards,
+    /// 같은 카드가 두 번 나옴
+    DuplicateCard(Card),
+    /// 보드를 채울 카드가 덱에 부족함
+    NotEnoughCards,
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EquityError::NotEnoughPlayers => f.write_str("핸드가 2개 이상 필요합니다"),
+            EquityError::WrongHoleCards { player } => {
+                write!(f, "{}번째 핸드의 홀카드가 2장이 아닙니다", player + 1)
+            }
+            EquityError::TooManyBoardCards => f.write_str("보드는 5장까지입니다"),
+            EquityError::DuplicateCard(card) => write!(f, "중복된 카드: {}", card),
+            EquityError::NotEnoughCards => f.write_str("보드를 채울 카드가 부족합니다"),
+        }
+    }
+}
+
+impl std::error::Error for EquityError {}
+
+/// 홀카드, 보드 일부, 죽은 카드로 각 핸드의 승/무/패 확률을 계산한다.
+/// 남은 경우가 적으면 전부 계산하고, 많으면 시드 고정 몬테카를로를 쓴다.
+pub fn calculate_equity(
+    hands: &[Vec<Card>],
+    board: &[Card],
+    dead: &[Card],
+    options: EquityOptions,
+) -> Result<EquityReport, EquityError> {
+    if hands.len() < 2 {
+        return Err(EquityError::NotEnoughPlayers);
+    }
+    if let Some(player) = hands.iter().position(|h| h.len() != 2) {
+        return Err(EquityError::WrongHoleCards { player });
+    }
+    if board.len() > 5 {
+        return Err(EquityError::TooManyBoardCards);
+    }
+
+    let mut used: Vec<Card> = Vec::new();
+    for card in hands.iter().flatten().chain(board).chain(dead) {
+        if used.contains(card) {
+            return Err(EquityError::DuplicateCard(*card));
+        }
+        used.push(*card);
+    }
+
+    let stub: Vec<Card> = Deck::new()
+        .cards
+        .into_iter()
+        .filter(|c| !used.contains(c))
+        .collect();
+    let missing = 5 - board.len();
+    if stub.len() < missing {
+        return Err(EquityError::NotEnoughCards);
+    }
+
+    let mut tally = Tally::new(hands.len());
+    // 보드가 다 나와 있으면 남은 카드가 없을 수도 있으니 아무 카드로 채워 둔다
+    let mut full_board = [Card::new(Rank::Two, Suit::Hearts); 5];
+    full_board[..board.len()].copy_from_slice(board);
+
+    let combos = combinations(stub.len() as u64, missing as u64);
+    let exhaustive = combos <= options.exhaustive_limit;
+    if exhaustive {
+        let mut idx: Vec<usize> = (0..missing).collect();
+        loop {
+            for (slot, &i) in idx.iter().enumerate() {
+                full_board[board.len() + slot] = stub[i];
+            }
+            tally.add(hands, &full_board);
+            if !next_combination(&mut idx, stub.len()) {
+                break;
+            }
+        }
+    } else {
+        let mut rng = ChaCha20Rng::seed_from_u64(options.seed);
+        let mut stub = stub;
+        for _ in 0..options.samples {
+            // 앞쪽 `missing`장만 부분 셔플
+            for slot in 0..missing {
+                let pick = rng.gen_range(slot..stub.len());
+                stub.swap(slot, pick);
+                full_board[board.len() + slot] = stub[slot];
+            }
+            tally.add(hands, &full_board);
+        }
+    }
+
+    Ok(tally.report(exhaustive))
+}
+
+/// 보드별 승/무 집계
+struct Tally {
+    wins: Vec<u64>,
+    ties: Vec<u64>,
+    /// 나눠 가진 팟 몫의 합
+    shares: Vec<f64>,
+    boards: u64,
+    strengths: Vec<HandStrength>,
+}
+
+impl Tally {
+    fn new(players: usize) -> Self {
+        Self {
+            wins: vec![0; players],
+            ties: vec![0; players],
+            shares: vec![0.0; players],
+            boards: 0,
+            strengths: Vec::with_capacity(players),
+        }
+    }
+
+    fn add(&mut self, hands: &[Vec<Card>], board: &[Card]) {
+        self.strengths.clear();
+        for hand in hands {
+            let cards = [hand[0], hand[1], board[0], board[1], board[2], board[3], board[4]];
+            self.strengths.push(seven_card_strength(&cards));
+        }
+
+        let best = *self.strengths.iter().max().unwrap();
+        let winners = self.strengths.iter().filter(|&&s| s == best).count();
+        for (i, strength) in self.strengths.iter().enumerate() {
+            if *strength != best {
+                continue;
+            }
+            if winners == 1 {
+                self.wins[i] += 1;
+            } else {
+                self.ties[i] += 1;
+            }
+            self.shares[i] += 1.0 / winners as f64;
+        }
+        self.boards += 1;
+    }
+
+    fn report(&self, exhaustive: bool) -> EquityReport {
+        let total = self.boards.max(1) as f64;
+        let players = (0..self.wins.len())
+            .map(|i| {
+                let win = self.wins[i] as f64 / total * 100.0;
+                let tie = self.ties[i] as f64 / total * 100.0;
+                PlayerEquity {
+                    win,
+                    tie,
+                    lose: 100.0 - win - tie,
+                    equity: self.shares[i] / total * 100.0,
+                }
+            })
+            .collect();
+
+        EquityReport {
+            players,
+            boards: self.boards,
+            exhaustive,
+        }
+    }
+}
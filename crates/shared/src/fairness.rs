@@ -0,0 +1,179 @@
+use crate::card::Deck;
+use crate::engine::{DealTarget, DealtCard};
+use crate::variant::Variant;
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+/// 서버 시드 (32바이트)
+pub type Seed = [u8; 32];
+
+/// 커밋-리빌 셔플.
+///
+/// 1. 서버가 시드를 정하고 그 시드로 섞은 덱 순서와 함께 해시를 공개한다 (커밋).
+/// 2. 클라이언트는 원하면 자기 엔트로피를 보탠다.
+/// 3. 실제 덱은 커밋된 덱을 (서버 시드 + 클라이언트 엔트로피)로 한 번 더 섞은 것이다.
+/// 4. 핸드가 끝나면 서버 시드와 각자 볼 수 있는 딜 기록([`visible_deals`])을 공개하고,
+///    누구나 [`verify_hand`]로 검증할 수 있다.
+#[derive(Debug, Clone)]
+pub struct ShuffleCommit {
+    server_seed: Seed,
+}
+
+impl ShuffleCommit {
+    /// OS 시드 RNG로 새 서버 시드를 만든다.
+    pub fn generate() -> Self {
+        Self::from_rng(&mut rand::thread_rng())
+    }
+
+    pub fn from_rng<R: Rng + CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let mut server_seed = [0u8; 32];
+        rng.fill(&mut server_seed);
+        Self { server_seed }
+    }
+
+    pub fn from_seed(server_seed: Seed) -> Self {
+        Self { server_seed }
+    }
+
+    /// 핸드 전에 공개하는 해시 (hex)
+    pub fn commitment(&self) -> String {
+        commitment_for(&self.server_seed)
+    }
+
+    /// 핸드가 끝난 뒤 공개하는 서버 시드 (hex)
+    pub fn reveal(&self) -> String {
+        to_hex(&self.server_seed)
+    }
+
+    /// 클라이언트 엔트로피까지 섞은 실제 덱
+    pub fn deck(&self, client_entropy: &[String]) -> Deck {
+        rebuild_deck(&self.server_seed, client_entropy)
+    }
+}
+
+/// 한 사람에게 공개하는 딜 기록 한 장
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevealedDeal {
+    /// 덱에서 몇 번째로 나간 카드인지 (0부터, 공개하지 않은 카드도 센다)
+    pub index: usize,
+    pub dealt: DealtCard,
+}
+
+/// `viewer`에게 공개할 딜 기록. 보드와 번 카드, 자기 홀카드, 쇼다운에서 보여 준(`shown`) 홀카드만 남긴다
+pub fn visible_deals(deal_log: &[DealtCard], viewer: &str, shown: &[String]) -> Vec<RevealedDeal> {
+    deal_log
+        .iter()
+        .enumerate()
+        .filter(|(_, dealt)| match &dealt.target {
+            DealTarget::Player(id) => id == viewer || shown.contains(id),
+            DealTarget::Burn | DealTarget::Board => true,
+        })
+        .map(|(index, dealt)| RevealedDeal {
+            index,
+            dealt: dealt.clone(),
+        })
+        .collect()
+}
+
+/// 검증 실패 이유
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FairnessError {
+    /// 공개된 시드가 32바이트 hex가 아님
+    InvalidSeed,
+    /// 시드가 커밋된 해시와 맞지 않음
+    CommitmentMismatch,
+    /// 기록된 카드가 덱 순서와 다름 (몇 번째 카드인지)
+    DealMismatch { index: usize },
+}
+
+impl fmt::Display for FairnessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FairnessError::InvalidSeed => f.write_str("잘못된 시드 형식입니다"),
+            FairnessError::CommitmentMismatch => f.write_str("시드가 커밋과 일치하지 않습니다"),
+            FairnessError::DealMismatch { index } => {
+                write!(f, "{}번째로 나간 카드가 덱 순서와 다릅니다", index + 1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FairnessError {}
+
+/// 서버 시드만으로 섞은 덱 (커밋 대상)
+pub fn committed_deck(server_seed: &Seed) -> Deck {
+    let mut deck = Deck::new();
+    deck.shuffle_with(&mut ChaCha20Rng::from_seed(*server_seed));
+    deck
+}
+
+/// SHA-256(서버 시드 || 커밋된 덱 순서)
+pub fn commitment_for(server_seed: &Seed) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(server_seed);
+    for card in &committed_deck(server_seed).cards {
+        hasher.update([card.index()]);
+    }
+    to_hex(&hasher.finalize())
+}
+
+/// 공개된 시드와 클라이언트 엔트로피로 실제 덱 순서를 다시 만든다.
+pub fn rebuild_deck(server_seed: &Seed, client_entropy: &[String]) -> Deck {
+    let mut hasher = Sha256::new();
+    hasher.update(server_seed);
+    for entropy in client_entropy {
+        // 길이를 앞에 붙여 경계가 모호하지 않게 한다
+        hasher.update((entropy.len() as u64).to_be_bytes());
+        hasher.update(entropy.as_bytes());
+    }
+    let mixed_seed: Seed = hasher.finalize().into();
+
+    let mut deck = committed_deck(server_seed);
+    deck.shuffle_with(&mut ChaCha20Rng::from_seed(mixed_seed));
+    deck
+}
+
+/// 커밋, 공개된 시드, 엔트로피, 딜 기록을 검증하고 실제 덱을 돌려준다.
+/// 커밋은 52장 기준이고, 게임 종류에 맞게 카드를 뺀 덱(`Variant::deck_from`)으로 딜 기록을 맞춰 본다.
+pub fn verify_hand(
+    variant: Variant,
+    commitment: &str,
+    server_seed_hex: &str,
+    client_entropy: &[String],
+    deal_log: &[RevealedDeal],
+) -> Result<Deck, FairnessError> {
+    let server_seed = parse_seed(server_seed_hex)?;
+    if commitment_for(&server_seed) != commitment.to_ascii_lowercase() {
+        return Err(FairnessError::CommitmentMismatch);
+    }
+
+    let deck = variant.deck_from(rebuild_deck(&server_seed, client_entropy));
+    // 딜은 덱의 맨 뒤부터 한 장씩 나간다
+    for RevealedDeal { index, dealt } in deal_log {
+        let expected = deck.cards.len().checked_sub(index + 1).map(|i| deck.cards[i]);
+        if expected != Some(dealt.card) {
+            return Err(FairnessError::DealMismatch { index: *index });
+        }
+    }
+
+    Ok(deck)
+}
+
+pub fn parse_seed(hex: &str) -> Result<Seed, FairnessError> {
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(FairnessError::InvalidSeed);
+    }
+    let mut seed = [0u8; 32];
+    for (i, byte) in seed.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .map_err(|_| FairnessError::InvalidSeed)?;
+    }
+    Ok(seed)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
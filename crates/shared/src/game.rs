@@ -1,4 +1,5 @@
 use crate::card::Card;
+use crate::config::TableConfig;
 use serde::{Deserialize, Serialize};
 
 /// 플레이어
@@ -9,8 +10,18 @@ pub struct Player {
     pub chips: i32,
     pub hand: Vec<Card>,
     pub bet: i32,
+    /// 이번 핸드 전체에서 베팅으로 팟에 넣은 금액 (사이드 팟 계산용, 앤티 제외)
+    pub total_bet: i32,
+    /// 이번 핸드에 낸 앤티. 데드 머니라 메인 팟에 들어가고 사이드 팟 단계에는 치지 않는다
+    pub ante: i32,
     pub folded: bool,
+    pub all_in: bool,
+    /// 이번 스트리트에서 마지막 풀 레이즈 이후 액션했는지
+    pub acted: bool,
+    /// 연결되어 있는지 (연결이 끊긴 뒤 재접속을 기다리는 동안 false)
     pub is_active: bool,
+    /// 쇼다운에서 홀카드를 공개했는지
+    pub show_cards: bool,
 }
 
 impl Player {
@@ -21,14 +32,165 @@ impl Player {
             chips,
             hand: Vec::new(),
             bet: 0,
+            total_bet: 0,
+            ante: 0,
             folded: false,
+            all_in: false,
+            acted: false,
             is_active: true,
+            show_cards: false,
         }
     }
+
+    /// 앤티를 낸다. 메인 팟에 들어가지만 베팅(`bet`, `total_bet`)에는 치지 않는다. 실제로 낸 금액을 반환한다.
+    pub fn post_ante(&mut self, amount: i32) -> i32 {
+        let amount = amount.clamp(0, self.chips);
+        self.chips -= amount;
+        self.ante += amount;
+        if self.chips == 0 {
+            self.all_in = true;
+        }
+        amount
+    }
+
+    /// 칩을 베팅에 넣는다. 스택보다 큰 금액은 올인으로 처리하고 실제로 넣은 금액을 반환한다.
+    pub fn commit_chips(&mut self, amount: i32) -> i32 {
+        let amount = amount.clamp(0, self.chips);
+        self.chips -= amount;
+        self.bet += amount;
+        self.total_bet += amount;
+        if self.chips == 0 {
+            self.all_in = true;
+        }
+        amount
+    }
+
+    /// 이번 핸드에 팟에 넣은 전체 금액 (앤티 포함)
+    pub fn contributed(&self) -> i32 {
+        self.total_bet + self.ante
+    }
+
+    /// 아직 베팅 액션을 할 수 있는지 (폴드/올인이 아닌 플레이어)
+    pub fn can_act(&self) -> bool {
+        !self.folded && !self.all_in
+    }
+}
+
+/// 메인 팟 / 사이드 팟
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pot {
+    pub amount: i32,
+    /// 이 팟을 가져갈 자격이 있는 플레이어 id
+    pub eligible: Vec<String>,
+}
+
+impl Pot {
+    /// 팟을 승자들에게 똑같이 나눈다. 나누어 떨어지지 않는 칩은
+    /// `winners` 순서대로(딜러 왼쪽 첫 자리부터) 한 칩씩 더 받는다.
+    pub fn split(&self, winners: &[String]) -> Vec<Payout> {
+        if winners.is_empty() {
+            return Vec::new();
+        }
+
+        let share = self.amount / winners.len() as i32;
+        let odd_chips = (self.amount % winners.len() as i32) as usize;
+
+        winners
+            .iter()
+            .enumerate()
+            .map(|(i, player_id)| Payout {
+                player_id: player_id.clone(),
+                amount: share + if i < odd_chips { 1 } else { 0 },
+            })
+            .collect()
+    }
+}
+
+/// 플레이어에게 지급되는 금액
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Payout {
+    pub player_id: String,
+    pub amount: i32,
+}
+
+/// 플레이어별 기여액으로 메인 팟과 사이드 팟을 순서대로 만든다.
+/// 폴드한 플레이어의 칩은 팟에 남지만 자격은 없다.
+/// 앤티는 데드 머니라 베팅 단계에는 치지 않고 메인 팟에 넣는다. 다만 앤티만 내고 올인한 플레이어는
+/// 자기가 낸 앤티만큼만 다른 사람의 앤티를 가져가도록 앤티도 낸 금액별로 나눈다.
+pub fn build_pots(players: &[Player]) -> Vec<Pot> {
+    let mut pots: Vec<Pot> = Vec::new();
+
+    // 앤티만 낸 플레이어의 앤티와 가장 큰 앤티가 단계가 된다
+    let mut ante_levels: Vec<i32> = players
+        .iter()
+        .filter(|p| !p.folded && p.total_bet == 0 && p.ante > 0)
+        .map(|p| p.ante)
+        .collect();
+    ante_levels.extend(players.iter().map(|p| p.ante).max().filter(|&ante| ante > 0));
+    ante_levels.sort_unstable();
+    ante_levels.dedup();
+
+    let mut prev = 0;
+    for level in ante_levels {
+        let amount: i32 = players
+            .iter()
+            .map(|p| p.ante.min(level) - p.ante.min(prev))
+            .sum();
+        // 베팅까지 한 플레이어는 앤티를 다 냈거나 (빅 블라인드 앤티처럼) 낼 필요가 없었다
+        let eligible: Vec<String> = players
+            .iter()
+            .filter(|p| !p.folded && (p.total_bet > 0 || p.ante >= level))
+            .map(|p| p.id.clone())
+            .collect();
+        pots.push(Pot { amount, eligible });
+        prev = level;
+    }
+
+    let mut levels: Vec<i32> = players
+        .iter()
+        .filter(|p| !p.folded && p.total_bet > 0)
+        .map(|p| p.total_bet)
+        .collect();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut prev = 0;
+    for level in levels {
+        let amount: i32 = players
+            .iter()
+            .map(|p| p.total_bet.min(level) - p.total_bet.min(prev))
+            .sum();
+        let eligible: Vec<String> = players
+            .iter()
+            .filter(|p| !p.folded && p.total_bet >= level)
+            .map(|p| p.id.clone())
+            .collect();
+        pots.push(Pot { amount, eligible });
+        prev = level;
+    }
+
+    // 남은 플레이어보다 많이 넣고 폴드한 칩은 마지막 팟에 포함
+    let dead: i32 = players.iter().map(|p| p.total_bet - p.total_bet.min(prev)).sum();
+    if dead > 0
+        && let Some(last) = pots.last_mut()
+    {
+        last.amount += dead;
+    }
+
+    // 자격이 같은 팟은 하나로 (앤티는 보통 메인 팟에 합쳐진다)
+    pots.dedup_by(|next, kept| {
+        let same = next.eligible == kept.eligible;
+        if same {
+            kept.amount += next.amount;
+        }
+        same
+    });
+
+    pots
 }
 
 /// 게임 단계
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GamePhase {
     Waiting,   // 대기 중
     PreFlop,   // 프리플랍 (홀카드만)
@@ -45,7 +207,124 @@ pub struct GameState {
     pub community_cards: Vec<Card>,
     pub pot: i32,
     pub current_bet: i32,
+    /// 최소 레이즈 금액 (마지막 레이즈 크기, 처음에는 빅 블라인드)
+    pub min_raise: i32,
     pub phase: GamePhase,
     pub current_player_idx: usize,
     pub dealer_idx: usize,
-}
\ No newline at end of file
+    pub config: TableConfig,
+}
+
+impl GameState {
+    pub fn new() -> Self {
+        Self {
+            players: Vec::new(),
+            community_cards: Vec::new(),
+            pot: 0,
+            current_bet: 0,
+            min_raise: 0,
+            phase: GamePhase::Waiting,
+            current_player_idx: 0,
+            dealer_idx: 0,
+            config: TableConfig::default(),
+        }
+    }
+
+    /// 특정 플레이어에게 보낼 상태. 본인 카드와 쇼다운에서 공개된 카드만 남긴다.
+    /// 테이블에 앉지 않은 관전자는 아무 홀카드도 볼 수 없다.
+    pub fn view_for(&self, viewer_id: &str) -> GameState {
+        let mut view = self.clone();
+        for player in &mut view.players {
+            if player.id != viewer_id && !player.show_cards {
+                player.hand.clear();
+            }
+        }
+        view
+    }
+
+    /// 베팅이 진행 중인 단계인지
+    pub fn is_betting(&self) -> bool {
+        matches!(
+            self.phase,
+            GamePhase::PreFlop | GamePhase::Flop | GamePhase::Turn | GamePhase::River
+        )
+    }
+
+    /// `from` 다음 자리부터 돌면서 이번 핸드에 참가한 (폴드하지 않은) 첫 플레이어를 찾는다.
+    pub fn next_in_hand_after(&self, from: usize) -> Option<usize> {
+        let len = self.players.len();
+        (1..=len)
+            .map(|offset| (from + offset) % len)
+            .find(|&idx| !self.players[idx].folded)
+    }
+
+    /// `from` 다음 자리부터 돌면서 칩이 남은 첫 플레이어를 찾는다. 버튼은 이 자리로만 옮긴다.
+    pub fn next_funded_after(&self, from: usize) -> Option<usize> {
+        let len = self.players.len();
+        (1..=len)
+            .map(|offset| (from + offset) % len)
+            .find(|&idx| self.players[idx].chips > 0)
+    }
+
+    /// 칩이 남은 플레이어 수
+    pub fn funded_players(&self) -> usize {
+        self.players.iter().filter(|p| p.chips > 0).count()
+    }
+
+    /// 블라인드 자리 (스몰, 빅). 둘이서 하면 버튼이 스몰 블라인드를 내고
+    /// 프리플랍에 먼저, 플랍부터는 나중에 액션한다.
+    pub fn blind_positions(&self) -> (usize, usize) {
+        let dealer = self.dealer_idx;
+        let small_blind = if self.contenders() == 2 && !self.players[dealer].folded {
+            dealer
+        } else {
+            self.next_in_hand_after(dealer).unwrap_or(dealer)
+        };
+        let big_blind = self.next_in_hand_after(small_blind).unwrap_or(small_blind);
+        (small_blind, big_blind)
+    }
+
+    /// `from` 다음 자리부터 돌면서 액션할 수 있는 첫 플레이어를 찾는다.
+    pub fn next_actor_after(&self, from: usize) -> Option<usize> {
+        let len = self.players.len();
+        (1..=len)
+            .map(|offset| (from + offset) % len)
+            .find(|&idx| self.players[idx].can_act())
+    }
+
+    /// `from` 다음 자리부터 돌면서 아직 액션이 필요한 첫 플레이어를 찾는다.
+    /// 베팅을 맞추지 못했거나 마지막 풀 레이즈 이후 액션하지 않은 플레이어가 대상이다.
+    pub fn next_to_act_after(&self, from: usize) -> Option<usize> {
+        let len = self.players.len();
+        (1..=len).map(|offset| (from + offset) % len).find(|&idx| {
+            let player = &self.players[idx];
+            player.can_act() && (player.bet < self.current_bet || !player.acted)
+        })
+    }
+
+    /// 이번 스트리트 베팅이 끝났는지. 액션할 수 있는 모든 플레이어가 베팅을 맞추고
+    /// 마지막 풀 레이즈 이후 한 번씩 액션해야 끝난다.
+    pub fn betting_round_complete(&self) -> bool {
+        let actors: Vec<&Player> = self.players.iter().filter(|p| p.can_act()).collect();
+        if actors.iter().any(|p| p.bet < self.current_bet) {
+            return false;
+        }
+        actors.len() <= 1 || actors.iter().all(|p| p.acted)
+    }
+
+    /// 딜러 왼쪽 첫 자리부터 시계 방향으로 센 자리 순서 (0부터)
+    pub fn seat_order(&self, idx: usize) -> usize {
+        let len = self.players.len();
+        (idx + len - self.dealer_idx - 1) % len
+    }
+
+    /// 아직 폴드하지 않은 플레이어 수
+    pub fn contenders(&self) -> usize {
+        self.players.iter().filter(|p| !p.folded).count()
+    }
+}
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
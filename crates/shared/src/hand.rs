@@ -1,9 +1,10 @@
 use crate::card::{Card, Rank};
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
 
 /// 핸드 랭킹
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum HandRank {
     HighCard = 1,
     OnePair = 2,
@@ -17,7 +18,29 @@ pub enum HandRank {
     RoyalFlush = 10,
 }
 
+/// 핸드 설명 언어
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    Korean,
+    English,
+}
+
 impl HandRank {
+    /// 낮은 족보부터
+    pub const ALL: [HandRank; 10] = [
+        HandRank::HighCard,
+        HandRank::OnePair,
+        HandRank::TwoPair,
+        HandRank::ThreeOfAKind,
+        HandRank::Straight,
+        HandRank::Flush,
+        HandRank::FullHouse,
+        HandRank::FourOfAKind,
+        HandRank::StraightFlush,
+        HandRank::RoyalFlush,
+    ];
+
     pub fn name(&self) -> &str {
         match self {
             HandRank::HighCard => "하이 카드",
@@ -32,6 +55,33 @@ impl HandRank {
             HandRank::RoyalFlush => "로얄 플러시",
         }
     }
+
+    pub fn name_in(&self, language: Language) -> &str {
+        if language == Language::Korean {
+            return self.name();
+        }
+        match self {
+            HandRank::HighCard => "High Card",
+            HandRank::OnePair => "One Pair",
+            HandRank::TwoPair => "Two Pair",
+            HandRank::ThreeOfAKind => "Three of a Kind",
+            HandRank::Straight => "Straight",
+            HandRank::Flush => "Flush",
+            HandRank::FullHouse => "Full House",
+            HandRank::FourOfAKind => "Four of a Kind",
+            HandRank::StraightFlush => "Straight Flush",
+            HandRank::RoyalFlush => "Royal Flush",
+        }
+    }
+
+    /// 키커를 빼고 족보를 이루는 `values` 항목 수
+    fn made_values(&self) -> usize {
+        match self {
+            HandRank::TwoPair | HandRank::FullHouse => 2,
+            HandRank::Flush => 5,
+            _ => 1,
+        }
+    }
 }
 
 /// 핸드 평가 결과
@@ -41,6 +91,142 @@ pub struct HandValue {
     pub values: Vec<u8>,
 }
 
+impl HandValue {
+    /// 족보와 키커까지 포함한 설명
+    /// ("Two Pair, Kings and Sevens, Ace kicker" / "투 페어, K 페어와 7 페어, A 키커")
+    pub fn describe(&self, language: Language) -> String {
+        let name = self.rank.name_in(language);
+        let v = &self.values;
+        let rank = |i: usize| RankName(v[i], language);
+        let kickers = |from: usize| Kickers(&v[from.min(v.len())..], language);
+
+        match (language, self.rank) {
+            (_, HandRank::RoyalFlush) => name.to_string(),
+            (Language::English, HandRank::HighCard) => {
+                format!("{}, {}-high{}", name, rank(0), kickers(1))
+            }
+            (Language::English, HandRank::OnePair | HandRank::ThreeOfAKind | HandRank::FourOfAKind) => {
+                format!("{}, {:#}{}", name, rank(0), kickers(1))
+            }
+            (Language::English, HandRank::TwoPair) => {
+                format!("{}, {:#} and {:#}{}", name, rank(0), rank(1), kickers(2))
+            }
+            (Language::English, HandRank::Straight | HandRank::StraightFlush) => {
+                format!("{}, {}-high", name, rank(0))
+            }
+            (Language::English, HandRank::FullHouse) => {
+                format!("{}, {:#} full of {:#}", name, rank(0), rank(1))
+            }
+            (_, HandRank::Flush) => format!("{}, {}", name, Kickers(v, language).joined()),
+            (Language::Korean, HandRank::HighCard) => {
+                format!("{}, {} 하이{}", name, rank(0), kickers(1))
+            }
+            (Language::Korean, HandRank::OnePair | HandRank::ThreeOfAKind | HandRank::FourOfAKind) => {
+                format!("{}, {}{}", name, rank(0), kickers(1))
+            }
+            (Language::Korean, HandRank::TwoPair) => {
+                format!("{}, {} 페어와 {} 페어{}", name, rank(0), rank(1), kickers(2))
+            }
+            (Language::Korean, HandRank::Straight | HandRank::StraightFlush) => {
+                format!("{}, {} 하이", name, rank(0))
+            }
+            (Language::Korean, HandRank::FullHouse) => {
+                format!("{}, {} 트리플에 {} 페어", name, rank(0), rank(1))
+            }
+        }
+    }
+
+    /// `best`(이 값을 만든 5장) 중 승부를 가른 카드.
+    ///
+    /// 족보를 이루는 카드는 항상 포함하고, 키커는 `runner_up`과 처음 달라지는 곳까지 포함한다.
+    /// `runner_up`이 없거나 족보가 다르면 키커는 넣지 않는다.
+    pub fn deciding_cards(&self, best: &[Card], runner_up: Option<&HandValue>) -> Vec<Card> {
+        // 스트레이트 계열은 5장이 한 덩어리
+        if matches!(
+            self.rank,
+            HandRank::Straight | HandRank::StraightFlush | HandRank::RoyalFlush
+        ) {
+            return best.to_vec();
+        }
+
+        let mut needed = self.rank.made_values();
+        if let Some(other) = runner_up
+            && other.rank == self.rank
+        {
+            needed = match self.values.iter().zip(&other.values).position(|(a, b)| a != b) {
+                Some(diff) => needed.max(diff + 1),
+                None => self.values.len(),
+            };
+        }
+
+        let deciding = &self.values[..needed.min(self.values.len())];
+        best.iter()
+            .filter(|c| {
+                // 에이스를 1로 세는 게임도 있다
+                deciding.contains(&(c.rank as u8)) || (c.rank == Rank::Ace && deciding.contains(&1))
+            })
+            .copied()
+            .collect()
+    }
+}
+
+impl fmt::Display for HandValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe(Language::Korean))
+    }
+}
+
+/// 설명에 쓰는 랭크 이름. `{:#}`는 영어 복수형 ("Kings")
+struct RankName(u8, Language);
+
+impl fmt::Display for RankName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(rank) = Rank::from_value(self.0) else {
+            return write!(f, "{}", self.0);
+        };
+        if self.1 == Language::Korean {
+            return f.write_str(rank.symbol());
+        }
+        let (singular, plural) = match rank {
+            Rank::Two => ("Two", "Twos"),
+            Rank::Three => ("Three", "Threes"),
+            Rank::Four => ("Four", "Fours"),
+            Rank::Five => ("Five", "Fives"),
+            Rank::Six => ("Six", "Sixes"),
+            Rank::Seven => ("Seven", "Sevens"),
+            Rank::Eight => ("Eight", "Eights"),
+            Rank::Nine => ("Nine", "Nines"),
+            Rank::Ten => ("Ten", "Tens"),
+            Rank::Jack => ("Jack", "Jacks"),
+            Rank::Queen => ("Queen", "Queens"),
+            Rank::King => ("King", "Kings"),
+            Rank::Ace => ("Ace", "Aces"),
+        };
+        f.write_str(if f.alternate() { plural } else { singular })
+    }
+}
+
+/// ", Ace-King kickers" / ", A-K 키커". 키커가 없으면 빈 문자열
+struct Kickers<'a>(&'a [u8], Language);
+
+impl Kickers<'_> {
+    fn joined(&self) -> String {
+        let names: Vec<String> = self.0.iter().map(|&v| RankName(v, self.1).to_string()).collect();
+        names.join("-")
+    }
+}
+
+impl fmt::Display for Kickers<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.0.len(), self.1) {
+            (0, _) => Ok(()),
+            (1, Language::English) => write!(f, ", {} kicker", self.joined()),
+            (_, Language::English) => write!(f, ", {} kickers", self.joined()),
+            (_, Language::Korean) => write!(f, ", {} 키커", self.joined()),
+        }
+    }
+}
+
 impl PartialOrd for HandValue {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -56,16 +242,55 @@ impl Ord for HandValue {
     }
 }
 
-/// 핸드 평가 함수
-pub fn evaluate_hand(cards: &[Card]) -> HandValue {
+/// 핸드를 평가할 수 없는 이유
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HandError {
+    /// 필요한 장수와 다름
+    WrongCardCount { expected: usize, got: usize },
+    /// 같은 카드가 두 번 들어옴
+    DuplicateCard(Card),
+}
+
+impl fmt::Display for HandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandError::WrongCardCount { expected, got } => {
+                write!(f, "카드 {}장이 필요하지만 {}장입니다", expected, got)
+            }
+            HandError::DuplicateCard(card) => write!(f, "중복된 카드: {}", card),
+        }
+    }
+}
+
+impl std::error::Error for HandError {}
+
+pub(crate) fn check_duplicates(cards: &[Card]) -> Result<(), HandError> {
+    for (i, card) in cards.iter().enumerate() {
+        if cards[..i].contains(card) {
+            return Err(HandError::DuplicateCard(*card));
+        }
+    }
+    Ok(())
+}
+
+/// 핸드 평가 함수 (정확히 5장)
+pub fn evaluate_hand(cards: &[Card]) -> Result<HandValue, HandError> {
+    if cards.len() != 5 {
+        return Err(HandError::WrongCardCount {
+            expected: 5,
+            got: cards.len(),
+        });
+    }
+    check_duplicates(cards)?;
+
     let mut sorted_cards = cards.to_vec();
-    sorted_cards.sort_by(|a, b| b.rank.cmp(&a.rank));
+    sorted_cards.sort_by_key(|c| Reverse(c.rank));
 
     // 플러시 체크
     let is_flush = cards.iter().all(|c| c.suit == cards[0].suit);
 
-    // 스트레이트 체크
-    let is_straight = check_straight(&sorted_cards);
+    // 스트레이트 체크 (A-2-3-4-5는 5 하이)
+    let straight_high = straight_high(&sorted_cards);
 
     // 랭크별 카운트
     let mut rank_counts = std::collections::HashMap::new();
@@ -77,123 +302,130 @@ pub fn evaluate_hand(cards: &[Card]) -> HandValue {
     counts.sort_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
 
     // 로얄 플러시
-    if is_flush && is_straight && sorted_cards[0].rank == Rank::Ace {
-        return HandValue {
+    if is_flush && straight_high == Some(Rank::Ace as u8) {
+        return Ok(HandValue {
             rank: HandRank::RoyalFlush,
             values: vec![14],
-        };
+        });
     }
 
     // 스트레이트 플러시
-    if is_flush && is_straight {
-        return HandValue {
+    if let Some(high) = straight_high
+        && is_flush
+    {
+        return Ok(HandValue {
             rank: HandRank::StraightFlush,
-            values: vec![sorted_cards[0].rank as u8],
-        };
+            values: vec![high],
+        });
     }
 
     // 포카드
     if counts[0].1 == 4 {
-        return HandValue {
+        return Ok(HandValue {
             rank: HandRank::FourOfAKind,
             values: vec![counts[0].0 as u8, counts[1].0 as u8],
-        };
+        });
     }
 
     // 풀 하우스
     if counts[0].1 == 3 && counts[1].1 == 2 {
-        return HandValue {
+        return Ok(HandValue {
             rank: HandRank::FullHouse,
             values: vec![counts[0].0 as u8, counts[1].0 as u8],
-        };
+        });
     }
 
     // 플러시
     if is_flush {
         let values: Vec<u8> = sorted_cards.iter().map(|c| c.rank as u8).collect();
-        return HandValue {
+        return Ok(HandValue {
             rank: HandRank::Flush,
             values,
-        };
+        });
     }
 
     // 스트레이트
-    if is_straight {
-        return HandValue {
+    if let Some(high) = straight_high {
+        return Ok(HandValue {
             rank: HandRank::Straight,
-            values: vec![sorted_cards[0].rank as u8],
-        };
+            values: vec![high],
+        });
     }
 
     // 트리플
     if counts[0].1 == 3 {
         let mut values = vec![counts[0].0 as u8];
-        for i in 1..counts.len() {
-            values.push(counts[i].0 as u8);
+        for (rank, _) in counts.iter().skip(1) {
+            values.push(*rank as u8);
         }
-        return HandValue {
+        return Ok(HandValue {
             rank: HandRank::ThreeOfAKind,
             values,
-        };
+        });
     }
 
     // 투 페어
     if counts[0].1 == 2 && counts[1].1 == 2 {
-        return HandValue {
+        return Ok(HandValue {
             rank: HandRank::TwoPair,
             values: vec![counts[0].0 as u8, counts[1].0 as u8, counts[2].0 as u8],
-        };
+        });
     }
 
     // 원 페어
     if counts[0].1 == 2 {
         let mut values = vec![counts[0].0 as u8];
-        for i in 1..counts.len() {
-            values.push(counts[i].0 as u8);
+        for (rank, _) in counts.iter().skip(1) {
+            values.push(*rank as u8);
         }
-        return HandValue {
+        return Ok(HandValue {
             rank: HandRank::OnePair,
             values,
-        };
+        });
     }
 
     // 하이 카드
     let values: Vec<u8> = sorted_cards.iter().map(|c| c.rank as u8).collect();
-    HandValue {
+    Ok(HandValue {
         rank: HandRank::HighCard,
         values,
-    }
+    })
 }
 
-fn check_straight(cards: &[Card]) -> bool {
+/// 내림차순 정렬된 5장이 스트레이트면 가장 높은 카드 랭크
+fn straight_high(cards: &[Card]) -> Option<u8> {
     if cards.len() < 5 {
-        return false;
+        return None;
     }
 
-    // A-2-3-4-5 스트레이트 체크 (Ace low)
+    // A-2-3-4-5 스트레이트 체크 (Ace low, 5 하이)
     if cards[0].rank == Rank::Ace
         && cards[1].rank == Rank::Five
         && cards[2].rank == Rank::Four
         && cards[3].rank == Rank::Three
         && cards[4].rank == Rank::Two
     {
-        return true;
+        return Some(Rank::Five as u8);
     }
 
     // 일반 스트레이트
     for i in 0..cards.len() - 1 {
         if cards[i].rank as u8 != cards[i + 1].rank as u8 + 1 {
-            return false;
+            return None;
         }
     }
-    true
+    Some(cards[0].rank as u8)
 }
 
 /// 최고의 5장 카드 조합 찾기 (7장 중)
-pub fn find_best_hand(cards: &[Card]) -> (Vec<Card>, HandValue) {
+pub fn find_best_hand(cards: &[Card]) -> Result<(Vec<Card>, HandValue), HandError> {
     if cards.len() < 5 {
-        panic!("Need at least 5 cards");
+        return Err(HandError::WrongCardCount {
+            expected: 5,
+            got: cards.len(),
+        });
     }
+    check_duplicates(cards)?;
 
     let mut best_hand = Vec::new();
     let mut best_value = HandValue {
@@ -208,7 +440,7 @@ pub fn find_best_hand(cards: &[Card]) -> (Vec<Card>, HandValue) {
                 for l in k + 1..cards.len() {
                     for m in l + 1..cards.len() {
                         let hand = vec![cards[i], cards[j], cards[k], cards[l], cards[m]];
-                        let value = evaluate_hand(&hand);
+                        let value = evaluate_hand(&hand)?;
                         if value > best_value {
                             best_value = value;
                             best_hand = hand;
@@ -219,5 +451,5 @@ pub fn find_best_hand(cards: &[Card]) -> (Vec<Card>, HandValue) {
         }
     }
 
-    (best_hand, best_value)
+    Ok((best_hand, best_value))
 }
\ No newline at end of file
@@ -1,10 +1,31 @@
 pub mod card;
+pub mod combinatorics;
+pub mod config;
+pub mod engine;
+pub mod equity;
+pub mod fairness;
 pub mod game;
 pub mod hand;
+pub mod lobby;
 pub mod protocol;
+pub mod range;
+pub mod strength;
+pub mod variant;
 
 // 자주 사용되는 타입들을 re-export
-pub use card::{Card, Deck, Rank, Suit};
-pub use game::{GamePhase, GameState, Player};
-pub use hand::{evaluate_hand, find_best_hand, HandRank, HandValue};
-pub use protocol::{ClientMessage, ServerMessage};
\ No newline at end of file
+pub use card::{parse_cards, Card, CardSet, Deck, DeckSpec, ParseCardError, Rank, Suit};
+pub use combinatorics::{category_odds, combinations, event_probability, permutations, CategoryOdds, Probability};
+pub use config::{Ante, ConfigError, TableConfig};
+pub use engine::{Action, DealTarget, DealtCard, ShowdownHand, Table, TableError, TableEvent};
+pub use equity::{calculate_equity, EquityError, EquityOptions, EquityReport, PlayerEquity};
+pub use fairness::{verify_hand, visible_deals, FairnessError, RevealedDeal, ShuffleCommit};
+pub use game::{build_pots, GamePhase, GameState, Payout, Player, Pot};
+pub use hand::{evaluate_hand, find_best_hand, HandError, HandRank, HandValue, Language};
+pub use lobby::{HandHistory, PlayerInfo, TableDetail, TableId, TableSummary};
+pub use protocol::{ClientMessage, ServerMessage, MAX_ENTROPY_LEN};
+pub use range::{range_equity, Range, RangeError, WeightedCombo};
+pub use strength::{evaluate_strength, HandStrength};
+pub use variant::{
+    best_omaha_hand, best_omaha_low, best_one_to_ten_hand, best_short_deck_hand, evaluate_short_deck_hand,
+    LowHand, Variant,
+};
\ No newline at end of file
@@ -0,0 +1,54 @@
+use crate::config::TableConfig;
+use crate::game::{GamePhase, GameState};
+use serde::{Deserialize, Serialize};
+
+/// 테이블 id (서버 안에서 1부터 차례로 붙인다)
+pub type TableId = u64;
+
+/// 로비 목록의 테이블 한 줄
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSummary {
+    pub id: TableId,
+    pub config: TableConfig,
+    /// 앉은 플레이어 수
+    pub seated: usize,
+    pub phase: GamePhase,
+    /// 지금까지 끝난 핸드 수
+    pub hands_played: u64,
+}
+
+impl TableSummary {
+    pub fn is_full(&self) -> bool {
+        self.seated >= self.config.seats
+    }
+}
+
+/// 테이블 하나의 공개 정보. 상태는 쇼다운에서 공개된 카드 말고는 홀카드를 가린다
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableDetail {
+    pub summary: TableSummary,
+    pub state: GameState,
+}
+
+/// 플레이어 한 명의 핸드 결과
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandHistory {
+    pub table_id: TableId,
+    /// 그 테이블에서 몇 번째 핸드인지 (1부터)
+    pub hand: u64,
+    /// 딴 금액 - 낸 금액
+    pub net: i32,
+    /// 핸드가 끝난 뒤 스택
+    pub chips: i32,
+}
+
+/// 플레이어 칩과 기록
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerInfo {
+    pub id: String,
+    pub name: String,
+    /// 지금 앉아 있는 테이블 (떠났으면 `None`)
+    pub table_id: Option<TableId>,
+    pub chips: i32,
+    pub history: Vec<HandHistory>,
+}
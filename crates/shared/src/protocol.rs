@@ -1,12 +1,27 @@
 use crate::card::Card;
-use crate::game::GameState;
+use crate::config::TableConfig;
+use crate::engine::{ShowdownHand, TableError};
+use crate::fairness::RevealedDeal;
+use crate::game::{GameState, Payout};
+use crate::lobby::TableId;
+use crate::variant::Variant;
 use serde::{Deserialize, Serialize};
 
+/// `AddEntropy`로 보낼 수 있는 엔트로피 최대 길이 (바이트)
+pub const MAX_ENTROPY_LEN: usize = 256;
+
 /// 클라이언트 -> 서버 메시지
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ClientMessage {
-    /// 게임 참가
-    Join { name: String },
+    /// 테이블에 앉는다. 테이블을 정하지 않으면 기본 테이블, 바이인을 정하지 않으면 테이블 시작 스택으로 앉는다.
+    /// 다른 테이블에 앉아 있었으면 그 테이블에서는 일어난다
+    Join {
+        #[serde(default)]
+        table_id: Option<TableId>,
+        name: String,
+        #[serde(default)]
+        buy_in: Option<i32>,
+    },
     
     /// 게임 시작 준비
     Ready,
@@ -22,15 +37,53 @@ pub enum ClientMessage {
     
     /// 레이즈 (베팅 올리기)
     Raise { amount: i32 },
+    
+    /// 다음 핸드 셔플에 보탤 엔트로피 (마지막으로 받은 `ShuffleCommitment`의 핸드에 들어간다)
+    AddEntropy { entropy: String },
+    
+    /// 테이블 게임 종류 변경 (핸드 사이에만). 테이블을 만든 플레이어가 아니면 관리자 토큰이 필요하다
+    SetVariant {
+        variant: Variant,
+        #[serde(default)]
+        admin_token: Option<String>,
+    },
+    
+    /// 연결이 끊긴 뒤 `Welcome`에서 받은 토큰으로 자리에 돌아간다
+    Resume { token: String },
+    
+    /// 새 테이블 만들기 (앉으려면 `Join`). 한 사람이 열어 둘 수 있는 테이블 수는 제한되고,
+    /// 이렇게 만든 테이블은 아무도 앉아 있지 않은 채로 몇 분이 지나면 닫힌다
+    CreateTable { config: TableConfig },
+    
+    /// 앉아 있는 테이블 설정 변경 (블라인드, 앤티, 스택, 좌석 - 핸드 사이에만).
+    /// 테이블을 만든 플레이어가 아니면 관리자 토큰이 필요하다
+    SetConfig {
+        config: TableConfig,
+        #[serde(default)]
+        admin_token: Option<String>,
+    },
 }
 
 /// 서버 -> 클라이언트 메시지
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ServerMessage {
-    /// 연결 환영 메시지
+    /// 연결 환영 메시지. `token`은 재접속(`Resume`)에 쓴다
     Welcome { 
         player_id: String, 
-        chips: i32 
+        chips: i32,
+        token: String 
+    },
+    
+    /// 새 테이블이 만들어짐 (`CreateTable`에 대한 응답)
+    TableCreated { 
+        table_id: TableId, 
+        config: TableConfig 
+    },
+    
+    /// 앉은 테이블의 설정 (참가했을 때, 설정이 바뀌었을 때)
+    TableInfo { 
+        table_id: TableId, 
+        config: TableConfig 
     },
     
     /// 게임 상태 업데이트
@@ -47,10 +100,31 @@ pub enum ServerMessage {
         action: String 
     },
     
-    /// 게임 종료
+    /// 게임 종료 (스플릿 팟이면 승자가 여럿). 쇼다운이 없었으면 `showdown`은 비어 있다
     GameOver { 
-        winner_id: String, 
-        amount: i32 
+        winners: Vec<Payout>,
+        showdown: Vec<ShowdownHand> 
+    },
+    
+    /// 다음 핸드 셔플의 커밋 해시 (핸드 전에 공개).
+    /// 핸드 중에 재접속하면 진행 중인 핸드 커밋과 다음 핸드 커밋을 차례로 보낸다 (항상 마지막 것이 다음 핸드)
+    ShuffleCommitment { 
+        commitment: String 
+    },
+    
+    /// 핸드 종료 후 셔플 공개 (검증은 `fairness::verify_hand`).
+    /// 딜 기록은 받는 사람마다 다르다: 남이 보여 주지 않은 홀카드는 빠진다 (`fairness::visible_deals`)
+    ShuffleReveal { 
+        variant: Variant,
+        commitment: String, 
+        server_seed: String, 
+        client_entropy: Vec<String>, 
+        deal_log: Vec<RevealedDeal> 
+    },
+    
+    /// 규칙에 맞지 않는 액션 거부
+    ActionRejected { 
+        error: TableError 
     },
     
     /// 에러 메시지
@@ -0,0 +1,388 @@
+use crate::card::{Card, Deck, Rank, Suit};
+use crate::combinatorics::{combinations, next_combination};
+use crate::equity::{EquityOptions, EquityReport, PlayerEquity};
+use crate::strength::seven_card_strength;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 가중치가 붙은 홀카드 2장
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WeightedCombo {
+    pub cards: [Card; 2],
+    pub weight: f64,
+}
+
+impl WeightedCombo {
+    fn blocked_by(&self, cards: &[Card]) -> bool {
+        cards.contains(&self.cards[0]) || cards.contains(&self.cards[1])
+    }
+}
+
+/// 핸드 레인지 ("QQ+, AKs, ATo+, 76s-54s")
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Range {
+    pub combos: Vec<WeightedCombo>,
+}
+
+/// 레인지 문법 오류나 계산할 수 없는 입력
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RangeError {
+    /// 해석할 수 없는 항목
+    InvalidToken(String),
+    /// 카드 제거 후 남은 조합이 없음
+    EmptyRange,
+    /// 보드가 5장 초과
+    TooManyBoardCards,
+    /// 보드와 죽은 카드에 같은 카드가 두 번 나옴
+    DuplicateCard(Card),
+    /// 보드를 채울 카드가 덱에 부족함
+    NotEnoughCards,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::InvalidToken(token) => write!(f, "잘못된 레인지 표기: {}", token),
+            RangeError::EmptyRange => f.write_str("가능한 조합이 없는 레인지입니다"),
+            RangeError::TooManyBoardCards => f.write_str("보드는 5장까지입니다"),
+            RangeError::DuplicateCard(card) => write!(f, "중복된 카드: {}", card),
+            RangeError::NotEnoughCards => f.write_str("보드를 채울 카드가 부족합니다"),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+/// 랭크 2개와 수딧 여부로 나타낸 핸드 종류 (AKs, QQ 등)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Suitedness {
+    Suited,
+    Offsuit,
+    Any,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct HandClass {
+    high: u8,
+    low: u8,
+    suitedness: Suitedness,
+}
+
+impl Range {
+    /// 표준 레인지 표기를 해석한다. 항목은 쉼표로 구분하고 `:0.5`처럼 가중치를 붙일 수 있다.
+    /// 같은 조합이 여러 번 나오면 나중 가중치를 쓴다.
+    pub fn parse(text: &str) -> Result<Self, RangeError> {
+        let mut range = Range::default();
+        for token in text.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            let (body, weight) = match token.split_once(':') {
+                Some((body, weight)) => {
+                    let weight: f64 = weight
+                        .trim()
+                        .parse()
+                        .map_err(|_| RangeError::InvalidToken(token.to_string()))?;
+                    if !(0.0..=1.0).contains(&weight) {
+                        return Err(RangeError::InvalidToken(token.to_string()));
+                    }
+                    (body.trim(), weight)
+                }
+                None => (token, 1.0),
+            };
+
+            let cards = expand(body).ok_or_else(|| RangeError::InvalidToken(token.to_string()))?;
+            for pair in cards {
+                range.insert(WeightedCombo { cards: pair, weight });
+            }
+        }
+        Ok(range)
+    }
+
+    fn insert(&mut self, combo: WeightedCombo) {
+        let same = |c: &WeightedCombo| {
+            (c.cards[0] == combo.cards[0] && c.cards[1] == combo.cards[1])
+                || (c.cards[0] == combo.cards[1] && c.cards[1] == combo.cards[0])
+        };
+        match self.combos.iter_mut().find(|c| same(c)) {
+            Some(existing) => existing.weight = combo.weight,
+            None if combo.weight > 0.0 => self.combos.push(combo),
+            None => {}
+        }
+    }
+
+    /// 보드나 죽은 카드와 겹치는 조합을 뺀 레인지
+    pub fn without(&self, cards: &[Card]) -> Range {
+        Range {
+            combos: self
+                .combos
+                .iter()
+                .filter(|c| !c.blocked_by(cards))
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// 가중치를 반영한 조합 수
+    pub fn weighted_count(&self) -> f64 {
+        self.combos.iter().map(|c| c.weight).sum()
+    }
+}
+
+fn parse_rank(c: char) -> Option<u8> {
+    Rank::from_char(c).map(|r| r as u8)
+}
+
+fn rank_of(value: u8) -> Rank {
+    Rank::from_value(value).unwrap_or(Rank::Ace)
+}
+
+/// "AK", "AKs", "AKo", "QQ"
+fn parse_class(text: &str) -> Option<HandClass> {
+    let chars: Vec<char> = text.chars().collect();
+    let (a, b) = match chars.as_slice() {
+        [a, b] | [a, b, _] => (parse_rank(*a)?, parse_rank(*b)?),
+        _ => return None,
+    };
+    let suitedness = match chars.get(2) {
+        None => Suitedness::Any,
+        Some('s') | Some('S') => Suitedness::Suited,
+        Some('o') | Some('O') => Suitedness::Offsuit,
+        Some(_) => return None,
+    };
+    // 페어는 수딧이 될 수 없다
+    if a == b && suitedness == Suitedness::Suited {
+        return None;
+    }
+    Some(HandClass {
+        high: a.max(b),
+        low: a.min(b),
+        suitedness,
+    })
+}
+
+fn class_combos(class: HandClass) -> Vec<[Card; 2]> {
+    let (high, low) = (rank_of(class.high), rank_of(class.low));
+    let mut combos = Vec::new();
+    for (i, &s1) in Suit::ALL.iter().enumerate() {
+        for (j, &s2) in Suit::ALL.iter().enumerate() {
+            let keep = if class.high == class.low {
+                i < j
+            } else {
+                match class.suitedness {
+                    Suitedness::Suited => i == j,
+                    Suitedness::Offsuit => i != j,
+                    Suitedness::Any => true,
+                }
+            };
+            if keep {
+                combos.push([Card::new(high, s1), Card::new(low, s2)]);
+            }
+        }
+    }
+    combos
+}
+
+/// 항목 하나를 조합 목록으로 펼친다.
+fn expand(body: &str) -> Option<Vec<[Card; 2]>> {
+    // "AhKh" 같은 특정 조합
+    let chars: Vec<char> = body.chars().collect();
+    if let [r1, s1, r2, s2] = chars.as_slice()
+        && let (Some(r1), Some(s1), Some(r2), Some(s2)) =
+            (Rank::from_char(*r1), Suit::from_char(*s1), Rank::from_char(*r2), Suit::from_char(*s2))
+    {
+        let first = Card::new(r1, s1);
+        let second = Card::new(r2, s2);
+        return (first != second).then(|| vec![[first, second]]);
+    }
+
+    let classes: Vec<HandClass> = if let Some((from, to)) = body.split_once('-') {
+        // "22-55", "A2s-A5s", "76s-54s"
+        let (a, b) = (parse_class(from)?, parse_class(to)?);
+        if a.suitedness != b.suitedness {
+            return None;
+        }
+        let (top, bottom) = if a.high >= b.high { (a, b) } else { (b, a) };
+        if top.high == top.low && bottom.high == bottom.low {
+            (bottom.high..=top.high)
+                .map(|r| HandClass { high: r, low: r, ..top })
+                .collect()
+        } else if top.high == bottom.high && top.low != top.high {
+            let (lo, hi) = (top.low.min(bottom.low), top.low.max(bottom.low));
+            (lo..=hi).map(|k| HandClass { low: k, ..top }).collect()
+        } else if top.high - top.low == bottom.high - bottom.low && top.low != top.high {
+            let gap = top.high - top.low;
+            (bottom.high..=top.high)
+                .map(|h| HandClass { high: h, low: h - gap, ..top })
+                .collect()
+        } else {
+            return None;
+        }
+    } else if let Some(base) = body.strip_suffix('+') {
+        // "QQ+", "ATo+"
+        let class = parse_class(base)?;
+        if class.high == class.low {
+            (class.high..=14)
+                .map(|r| HandClass { high: r, low: r, ..class })
+                .collect()
+        } else {
+            (class.low..class.high).map(|k| HandClass { low: k, ..class }).collect()
+        }
+    } else {
+        vec![parse_class(body)?]
+    };
+
+    Some(classes.into_iter().flat_map(class_combos).collect())
+}
+
+/// 두 레인지의 에퀴티. 보드와 죽은 카드에 걸리는 조합은 빼고 계산한다.
+/// 경우의 수가 적으면 (조합 쌍 × 남은 보드)를 전부 계산하고, 많으면 시드 고정 몬테카를로.
+pub fn range_equity(
+    hero: &Range,
+    villain: &Range,
+    board: &[Card],
+    dead: &[Card],
+    options: EquityOptions,
+) -> Result<EquityReport, RangeError> {
+    if board.len() > 5 {
+        return Err(RangeError::TooManyBoardCards);
+    }
+    let mut known: Vec<Card> = Vec::new();
+    for card in board.iter().chain(dead) {
+        if known.contains(card) {
+            return Err(RangeError::DuplicateCard(*card));
+        }
+        known.push(*card);
+    }
+    let hero = hero.without(&known);
+    let villain = villain.without(&known);
+
+    // 서로 겹치지 않는 조합 쌍
+    let mut matchups: Vec<(WeightedCombo, WeightedCombo)> = Vec::new();
+    for h in &hero.combos {
+        for v in &villain.combos {
+            if !v.blocked_by(&h.cards) {
+                matchups.push((*h, *v));
+            }
+        }
+    }
+    if matchups.is_empty() {
+        return Err(RangeError::EmptyRange);
+    }
+
+    // 두 조합을 빼고도 보드를 채울 카드가 남아야 한다
+    let missing = 5 - board.len();
+    let stub_len = 52usize
+        .checked_sub(known.len() + 4)
+        .filter(|&len| len >= missing)
+        .ok_or(RangeError::NotEnoughCards)?;
+    let boards_per_matchup = combinations(stub_len as u64, missing as u64);
+    let exhaustive = boards_per_matchup * matchups.len() as u64 <= options.exhaustive_limit;
+
+    let mut tally = WeightedTally::default();
+    let mut full_board = [Card::new(Rank::Two, Suit::Hearts); 5];
+    full_board[..board.len()].copy_from_slice(board);
+
+    if exhaustive {
+        for (h, v) in &matchups {
+            let stub = remaining(&known, h, v);
+            let mut idx: Vec<usize> = (0..missing).collect();
+            loop {
+                for (slot, &i) in idx.iter().enumerate() {
+                    full_board[board.len() + slot] = stub[i];
+                }
+                tally.add(h, v, &full_board, h.weight * v.weight);
+                if !next_combination(&mut idx, stub.len()) {
+                    break;
+                }
+            }
+        }
+    } else {
+        let mut rng = ChaCha20Rng::seed_from_u64(options.seed);
+        let total_weight: f64 = matchups.iter().map(|(h, v)| h.weight * v.weight).sum();
+        for _ in 0..options.samples {
+            // 가중치 비율로 조합 쌍을 고른다
+            let mut target = rng.gen_range(0.0..total_weight);
+            let (h, v) = matchups
+                .iter()
+                .find(|(h, v)| {
+                    target -= h.weight * v.weight;
+                    target < 0.0
+                })
+                .unwrap_or(&matchups[matchups.len() - 1]);
+
+            let mut stub = remaining(&known, h, v);
+            for slot in 0..missing {
+                let pick = rng.gen_range(slot..stub.len());
+                stub.swap(slot, pick);
+                full_board[board.len() + slot] = stub[slot];
+            }
+            tally.add(h, v, &full_board, 1.0);
+        }
+    }
+
+    Ok(tally.report(exhaustive))
+}
+
+fn remaining(known: &[Card], hero: &WeightedCombo, villain: &WeightedCombo) -> Vec<Card> {
+    Deck::new()
+        .cards
+        .into_iter()
+        .filter(|c| !known.contains(c) && !hero.blocked_by(&[*c]) && !villain.blocked_by(&[*c]))
+        .collect()
+}
+
+#[derive(Default)]
+struct WeightedTally {
+    win: [f64; 2],
+    tie: f64,
+    total: f64,
+    boards: u64,
+}
+
+impl WeightedTally {
+    fn add(&mut self, hero: &WeightedCombo, villain: &WeightedCombo, board: &[Card], weight: f64) {
+        let strength = |combo: &WeightedCombo| {
+            seven_card_strength(&[
+                combo.cards[0],
+                combo.cards[1],
+                board[0],
+                board[1],
+                board[2],
+                board[3],
+                board[4],
+            ])
+        };
+        let (h, v) = (strength(hero), strength(villain));
+        if h > v {
+            self.win[0] += weight;
+        } else if v > h {
+            self.win[1] += weight;
+        } else {
+            self.tie += weight;
+        }
+        self.total += weight;
+        self.boards += 1;
+    }
+
+    fn report(&self, exhaustive: bool) -> EquityReport {
+        let total = if self.total > 0.0 { self.total } else { 1.0 };
+        let tie = self.tie / total * 100.0;
+        let players = (0..2)
+            .map(|i| {
+                let win = self.win[i] / total * 100.0;
+                PlayerEquity {
+                    win,
+                    tie,
+                    lose: 100.0 - win - tie,
+                    equity: win + tie / 2.0,
+                }
+            })
+            .collect();
+
+        EquityReport {
+            players,
+            boards: self.boards,
+            exhaustive,
+        }
+    }
+}
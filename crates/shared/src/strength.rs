@@ -0,0 +1,182 @@
+use crate::card::Card;
+use crate::hand::{check_duplicates, HandError, HandRank, HandValue};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// 빠른 평가용 핸드 세기. 값이 클수록 강하고, `HandValue`의 순서와 정확히 같다.
+///
+/// 비트 구성: `[족보 4비트][값1 4비트][값2]...[값5 4비트]`
+/// 값은 `HandValue::values`와 같은 순서로 앞에서부터 채운다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct HandStrength(pub u32);
+
+impl HandStrength {
+    fn new(rank: HandRank, values: &[u8]) -> Self {
+        let mut code = rank as u32;
+        for i in 0..5 {
+            code = (code << 4) | values.get(i).copied().unwrap_or(0) as u32;
+        }
+        Self(code)
+    }
+
+    /// `HandValue`를 같은 순서의 세기로 바꾼다.
+    pub fn from_value(value: &HandValue) -> Self {
+        Self::new(value.rank, &value.values)
+    }
+
+    pub fn rank(&self) -> HandRank {
+        match self.0 >> 20 {
+            1 => HandRank::HighCard,
+            2 => HandRank::OnePair,
+            3 => HandRank::TwoPair,
+            4 => HandRank::ThreeOfAKind,
+            5 => HandRank::Straight,
+            6 => HandRank::Flush,
+            7 => HandRank::FullHouse,
+            8 => HandRank::FourOfAKind,
+            9 => HandRank::StraightFlush,
+            _ => HandRank::RoyalFlush,
+        }
+    }
+}
+
+/// 랭크 비트마스크(2 = bit 0 ... A = bit 12)별 스트레이트 최고 랭크. 없으면 0.
+fn straight_table() -> &'static [u8; 8192] {
+    static TABLE: OnceLock<Box<[u8; 8192]>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = Box::new([0u8; 8192]);
+        for (mask, high) in table.iter_mut().enumerate() {
+            for top in (4..13).rev() {
+                let run = 0b11111 << (top - 4);
+                if mask & run == run {
+                    *high = top as u8 + 2;
+                    break;
+                }
+            }
+            // A-2-3-4-5는 5 하이
+            const WHEEL: usize = 0b1_0000_0000_1111;
+            if *high == 0 && mask & WHEEL == WHEEL {
+                *high = 5;
+            }
+        }
+        table
+    })
+}
+
+/// 마스크에서 높은 랭크부터 `n`개
+fn top_ranks(mut mask: u16, n: usize, out: &mut [u8; 5], start: usize) {
+    for slot in out.iter_mut().skip(start).take(n) {
+        if mask == 0 {
+            break;
+        }
+        let bit = 15 - mask.leading_zeros() as u16;
+        *slot = bit as u8 + 2;
+        mask &= !(1 << bit);
+    }
+}
+
+fn bit(rank: u8) -> u16 {
+    1 << (rank - 2)
+}
+
+/// 5~7장(이상)에서 최고 5장의 세기를 바로 계산한다. 힙 할당이 없다.
+/// 결과는 `find_best_hand`가 고른 `HandValue`와 같은 순서를 가진다.
+pub fn evaluate_strength(cards: &[Card]) -> Result<HandStrength, HandError> {
+    if cards.len() < 5 {
+        return Err(HandError::WrongCardCount {
+            expected: 5,
+            got: cards.len(),
+        });
+    }
+    check_duplicates(cards)?;
+    Ok(strength_of(cards))
+}
+
+/// 홀카드 2장과 보드 5장의 세기. 장수는 타입이 보장하고 중복은 호출하는 쪽에서 이미 확인했다
+/// (에퀴티 계산처럼 같은 검사를 수백만 번 반복하지 않도록).
+pub(crate) fn seven_card_strength(cards: &[Card; 7]) -> HandStrength {
+    strength_of(cards)
+}
+
+fn strength_of(cards: &[Card]) -> HandStrength {
+    let mut suit_masks = [0u16; 4];
+    let mut counts = [0u8; 15];
+    for card in cards {
+        let rank = card.rank as u8;
+        suit_masks[card.suit.index() as usize] |= bit(rank);
+        counts[rank as usize] += 1;
+    }
+
+    let straights = straight_table();
+    let mut values = [0u8; 5];
+
+    // 스트레이트 플러시 / 로얄 플러시
+    let flush_mask = suit_masks.iter().copied().find(|m| m.count_ones() >= 5);
+    if let Some(mask) = flush_mask {
+        match straights[mask as usize] {
+            0 => {}
+            14 => return HandStrength::new(HandRank::RoyalFlush, &[14]),
+            high => return HandStrength::new(HandRank::StraightFlush, &[high]),
+        }
+    }
+
+    let rank_mask = suit_masks.iter().fold(0, |acc, m| acc | m);
+    let highest_with = |min_count: u8, except: &[u8]| {
+        (2..=14u8)
+            .rev()
+            .find(|&r| counts[r as usize] >= min_count && !except.contains(&r))
+    };
+
+    // 포카드
+    if let Some(quad) = highest_with(4, &[]) {
+        values[0] = quad;
+        top_ranks(rank_mask & !bit(quad), 1, &mut values, 1);
+        return HandStrength::new(HandRank::FourOfAKind, &values);
+    }
+
+    // 풀 하우스
+    let trips = highest_with(3, &[]);
+    if let Some(trip) = trips
+        && let Some(pair) = highest_with(2, &[trip])
+    {
+        return HandStrength::new(HandRank::FullHouse, &[trip, pair]);
+    }
+
+    // 플러시
+    if let Some(mask) = flush_mask {
+        top_ranks(mask, 5, &mut values, 0);
+        return HandStrength::new(HandRank::Flush, &values);
+    }
+
+    // 스트레이트
+    let high = straights[rank_mask as usize];
+    if high != 0 {
+        return HandStrength::new(HandRank::Straight, &[high]);
+    }
+
+    // 트리플
+    if let Some(trip) = trips {
+        values[0] = trip;
+        top_ranks(rank_mask & !bit(trip), 2, &mut values, 1);
+        return HandStrength::new(HandRank::ThreeOfAKind, &values);
+    }
+
+    if let Some(high_pair) = highest_with(2, &[]) {
+        // 투 페어
+        if let Some(low_pair) = highest_with(2, &[high_pair]) {
+            values[0] = high_pair;
+            values[1] = low_pair;
+            top_ranks(rank_mask & !bit(high_pair) & !bit(low_pair), 1, &mut values, 2);
+            return HandStrength::new(HandRank::TwoPair, &values);
+        }
+
+        // 원 페어
+        values[0] = high_pair;
+        top_ranks(rank_mask & !bit(high_pair), 3, &mut values, 1);
+        return HandStrength::new(HandRank::OnePair, &values);
+    }
+
+    // 하이 카드
+    top_ranks(rank_mask, 5, &mut values, 0);
+    HandStrength::new(HandRank::HighCard, &values)
+}
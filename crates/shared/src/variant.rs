@@ -0,0 +1,369 @@
+use crate::card::{Card, Deck, DeckSpec, Rank};
+use crate::combinatorics::for_each_combination;
+use crate::hand::{check_duplicates, evaluate_hand, find_best_hand, HandError, HandRank, HandValue};
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+
+/// 게임 종류. 홀카드 장수와 쇼다운 평가 방식이 달라진다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Variant {
+    /// 텍사스 홀덤: 홀카드 2장, 7장 중 아무 5장
+    #[default]
+    Holdem,
+    /// 오마하: 홀카드 4장, 반드시 홀카드 2장 + 보드 3장
+    Omaha,
+    /// 오마하 하이-로우: 오마하 + 8 이하 로우 핸드가 팟 절반을 가져간다
+    OmahaHiLo,
+    /// 숏덱 (6+) 홀덤: 2~5를 뺀 36장. 플러시가 풀 하우스를 이기고 A-6-7-8-9가 가장 낮은 스트레이트다
+    ShortDeck,
+    /// 1~10 하우스 게임 (docs/README.md): 40장 덱, 최대 4명, 홀카드 1장 + 데스크 2장.
+    /// 족보는 트리플 > 스트레이트 > 더블(원 페어) > 하이 카드 뿐이고 에이스는 1로 센다.
+    /// 문서에는 서열이 없어서 3장 기준 경우의 수가 적은 족보를 위로 둔다
+    /// (트리플 40, 스트레이트 512, 더블 2160 / 9880. docs/README.md 5, 7절).
+    OneToTen,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 5] = [
+        Variant::Holdem,
+        Variant::Omaha,
+        Variant::OmahaHiLo,
+        Variant::ShortDeck,
+        Variant::OneToTen,
+    ];
+
+    pub fn name(&self) -> &str {
+        match self {
+            Variant::Holdem => "텍사스 홀덤",
+            Variant::Omaha => "오마하",
+            Variant::OmahaHiLo => "오마하 하이-로우",
+            Variant::ShortDeck => "숏덱 홀덤",
+            Variant::OneToTen => "1~10 하우스",
+        }
+    }
+
+    /// 설정이나 명령줄에서 쓰는 이름
+    pub fn code(&self) -> &str {
+        match self {
+            Variant::Holdem => "holdem",
+            Variant::Omaha => "omaha",
+            Variant::OmahaHiLo => "omaha-hilo",
+            Variant::ShortDeck => "short-deck",
+            Variant::OneToTen => "one-to-ten",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Variant> {
+        Variant::ALL
+            .into_iter()
+            .find(|v| v.code().eq_ignore_ascii_case(code))
+    }
+
+    /// 한 사람이 받는 홀카드 장수
+    pub fn hole_cards(&self) -> usize {
+        match self {
+            Variant::Holdem | Variant::ShortDeck => 2,
+            Variant::Omaha | Variant::OmahaHiLo => 4,
+            Variant::OneToTen => 1,
+        }
+    }
+
+    /// 한 테이블에 앉을 수 있는 최대 인원
+    pub fn max_players(&self) -> usize {
+        match self {
+            Variant::Holdem | Variant::Omaha | Variant::OmahaHiLo | Variant::ShortDeck => 10,
+            Variant::OneToTen => 4,
+        }
+    }
+
+    /// 이 게임의 덱 구성
+    pub fn deck_spec(&self) -> DeckSpec {
+        match self {
+            Variant::Holdem | Variant::Omaha | Variant::OmahaHiLo => DeckSpec::standard(),
+            Variant::ShortDeck => DeckSpec::short(),
+            Variant::OneToTen => DeckSpec::one_to_ten(),
+        }
+    }
+
+    /// 이 게임에서 쓰는 카드인지
+    pub fn uses_card(&self, card: &Card) -> bool {
+        self.deck_spec().contains(card)
+    }
+
+    /// 52장을 섞은 덱에서 이 게임에 쓰지 않는 카드를 빼낸다. 남은 카드의 순서는 그대로다.
+    /// 셔플 커밋은 항상 52장 기준이므로 검증도 같은 방식으로 한다.
+    pub fn deck_from(&self, mut deck: Deck) -> Deck {
+        let spec = self.deck_spec();
+        deck.cards.retain(|c| spec.contains(c));
+        deck
+    }
+
+    /// 홀카드를 돌린 뒤 첫 베팅 전에 여는 보드 카드 수
+    pub fn opening_board_cards(&self) -> usize {
+        match self {
+            Variant::OneToTen => 1,
+            _ => 0,
+        }
+    }
+
+    /// 베팅 라운드가 끝날 때마다 여는 보드 카드 수. 마지막 라운드 뒤에는 쇼다운
+    pub fn streets(&self) -> &'static [usize] {
+        match self {
+            Variant::OneToTen => &[1],
+            _ => &[3, 1, 1],
+        }
+    }
+
+    /// 보드를 열기 전에 번 카드를 버리는지
+    pub fn burns(&self) -> bool {
+        *self != Variant::OneToTen
+    }
+
+    /// 족보의 서열. 게임마다 족보 순서가 다를 수 있다
+    pub fn category_order(&self, rank: HandRank) -> u8 {
+        match (self, rank) {
+            // 3장 게임에서는 트리플(40가지)이 스트레이트(512가지)보다 드물어서 위로 간다
+            (Variant::OneToTen, HandRank::Straight) => HandRank::ThreeOfAKind as u8,
+            (Variant::OneToTen, HandRank::ThreeOfAKind) => HandRank::Straight as u8,
+            // 숏덱은 플러시가 더 드물다
+            (Variant::ShortDeck, HandRank::Flush) => HandRank::FullHouse as u8,
+            (Variant::ShortDeck, HandRank::FullHouse) => HandRank::Flush as u8,
+            _ => rank as u8,
+        }
+    }
+
+    /// 이 게임의 족보 순서로 두 핸드를 비교한다.
+    pub fn compare_hands(&self, a: &HandValue, b: &HandValue) -> Ordering {
+        self.category_order(a.rank)
+            .cmp(&self.category_order(b.rank))
+            .then_with(|| a.values.cmp(&b.values))
+    }
+
+    /// 로우 핸드에 팟 절반을 나눠 주는지
+    pub fn has_low(&self) -> bool {
+        *self == Variant::OmahaHiLo
+    }
+
+    /// 하이 핸드 기준 최고 5장
+    pub fn best_hand(&self, hole: &[Card], board: &[Card]) -> Result<(Vec<Card>, HandValue), HandError> {
+        match self {
+            Variant::Holdem => {
+                let mut cards = hole.to_vec();
+                cards.extend_from_slice(board);
+                find_best_hand(&cards)
+            }
+            Variant::Omaha | Variant::OmahaHiLo => best_omaha_hand(hole, board),
+            Variant::ShortDeck => best_short_deck_hand(&[hole, board].concat()),
+            Variant::OneToTen => best_one_to_ten_hand(hole, board),
+        }
+    }
+
+    /// 자격을 갖춘 최고 로우 핸드. 로우가 없는 게임이거나 자격이 안 되면 `None`
+    pub fn best_low(&self, hole: &[Card], board: &[Card]) -> Option<LowHand> {
+        if !self.has_low() {
+            return None;
+        }
+        best_omaha_low(hole, board)
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 8 이하 로우 핸드 (에이스는 1). 서로 다른 랭크 5장이고 스트레이트/플러시는 상관없다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LowHand {
+    pub cards: Vec<Card>,
+    /// 높은 카드부터 내림차순. 사전순으로 작을수록 좋은 로우다
+    pub values: Vec<u8>,
+}
+
+impl LowHand {
+    /// 5장이 8 이하 로우 자격이 되면 로우 핸드를 만든다.
+    pub fn from_cards(cards: &[Card]) -> Option<LowHand> {
+        if cards.len() != 5 {
+            return None;
+        }
+        let mut values: Vec<u8> = cards.iter().map(|c| low_value(c.rank)).collect();
+        values.sort_by_key(|&v| Reverse(v));
+        if values[0] > 8 || values.windows(2).any(|w| w[0] == w[1]) {
+            return None;
+        }
+        Some(LowHand {
+            cards: cards.to_vec(),
+            values,
+        })
+    }
+
+    /// `other`보다 좋은 로우인지
+    pub fn beats(&self, other: &LowHand) -> bool {
+        self.values < other.values
+    }
+}
+
+/// "8-5-4-2-A 로우"
+impl fmt::Display for LowHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self
+            .values
+            .iter()
+            .map(|&v| match Rank::from_value(v) {
+                Some(rank) => rank.symbol().to_string(),
+                None => "A".to_string(),
+            })
+            .collect();
+        write!(f, "{} 로우", names.join("-"))
+    }
+}
+
+fn low_value(rank: Rank) -> u8 {
+    match rank {
+        Rank::Ace => 1,
+        other => other as u8,
+    }
+}
+
+/// 홀카드 2장 + 보드 3장 조합을 모두 돈다.
+fn omaha_hands(hole: &[Card], board: &[Card], mut visit: impl FnMut([Card; 5])) {
+    for i in 0..hole.len() {
+        for j in i + 1..hole.len() {
+            for a in 0..board.len() {
+                for b in a + 1..board.len() {
+                    for c in b + 1..board.len() {
+                        visit([hole[i], hole[j], board[a], board[b], board[c]]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 오마하 하이: 반드시 홀카드 2장과 보드 3장을 쓴다.
+pub fn best_omaha_hand(hole: &[Card], board: &[Card]) -> Result<(Vec<Card>, HandValue), HandError> {
+    if hole.len() < 2 {
+        return Err(HandError::WrongCardCount {
+            expected: 2,
+            got: hole.len(),
+        });
+    }
+    if board.len() < 3 {
+        return Err(HandError::WrongCardCount {
+            expected: 3,
+            got: board.len(),
+        });
+    }
+    check_duplicates(&[hole, board].concat())?;
+
+    let mut best: Option<(Vec<Card>, HandValue)> = None;
+    omaha_hands(hole, board, |hand| {
+        let value = evaluate_hand(&hand).expect("5장이고 중복은 위에서 걸렀다");
+        if best.as_ref().is_none_or(|(_, b)| value > *b) {
+            best = Some((hand.to_vec(), value));
+        }
+    });
+    Ok(best.expect("홀카드 2장, 보드 3장 이상이면 조합이 있다"))
+}
+
+/// 오마하 로우: 홀카드 2장 + 보드 3장으로 만들 수 있는 최고의 8 이하 로우
+pub fn best_omaha_low(hole: &[Card], board: &[Card]) -> Option<LowHand> {
+    let mut best: Option<LowHand> = None;
+    omaha_hands(hole, board, |hand| {
+        if let Some(low) = LowHand::from_cards(&hand)
+            && best.as_ref().is_none_or(|b| low.beats(b))
+        {
+            best = Some(low);
+        }
+    });
+    best
+}
+
+/// 1~10 하우스 게임: 홀카드 1장 + 데스크 2장, 정확히 3장으로 평가한다.
+///
+/// 족보는 `HandRank`로 나타내며 순서는 [`Variant::category_order`]를 따른다.
+/// 트리플 = `ThreeOfAKind`, 스트레이트(연속 3장, 1-2-3 ~ 8-9-10) = `Straight`,
+/// 더블 = `OnePair`, 나머지는 `HighCard`. 값에서 에이스는 1이다.
+pub fn best_one_to_ten_hand(hole: &[Card], board: &[Card]) -> Result<(Vec<Card>, HandValue), HandError> {
+    let cards = [hole, board].concat();
+    if cards.len() != 3 {
+        return Err(HandError::WrongCardCount {
+            expected: 3,
+            got: cards.len(),
+        });
+    }
+    check_duplicates(&cards)?;
+
+    let mut values: Vec<u8> = cards.iter().map(|c| low_value(c.rank)).collect();
+    values.sort_by_key(|&v| Reverse(v));
+    let (high, mid, low) = (values[0], values[1], values[2]);
+
+    let value = if high == low {
+        HandValue {
+            rank: HandRank::ThreeOfAKind,
+            values: vec![high],
+        }
+    } else if high == mid + 1 && mid == low + 1 {
+        HandValue {
+            rank: HandRank::Straight,
+            values: vec![high],
+        }
+    } else if high == mid || mid == low {
+        let kicker = if high == mid { low } else { high };
+        HandValue {
+            rank: HandRank::OnePair,
+            values: vec![mid, kicker],
+        }
+    } else {
+        HandValue {
+            rank: HandRank::HighCard,
+            values,
+        }
+    };
+    Ok((cards, value))
+}
+
+/// 숏덱 5장 평가. A-6-7-8-9를 9 하이 스트레이트로 본다 (2~5가 없으므로 A-2-3-4-5는 나올 수 없다).
+pub fn evaluate_short_deck_hand(cards: &[Card]) -> Result<HandValue, HandError> {
+    let value = evaluate_hand(cards)?;
+    let mut ranks: Vec<Rank> = cards.iter().map(|c| c.rank).collect();
+    ranks.sort();
+    if ranks != [Rank::Six, Rank::Seven, Rank::Eight, Rank::Nine, Rank::Ace] {
+        return Ok(value);
+    }
+
+    let rank = if value.rank == HandRank::Flush {
+        HandRank::StraightFlush
+    } else {
+        HandRank::Straight
+    };
+    Ok(HandValue {
+        rank,
+        values: vec![Rank::Nine as u8],
+    })
+}
+
+/// 숏덱 최고 5장. 족보 순서는 [`Variant::ShortDeck`]을 따른다
+pub fn best_short_deck_hand(cards: &[Card]) -> Result<(Vec<Card>, HandValue), HandError> {
+    if cards.len() < 5 {
+        return Err(HandError::WrongCardCount {
+            expected: 5,
+            got: cards.len(),
+        });
+    }
+    check_duplicates(cards)?;
+
+    let mut best: Option<(Vec<Card>, HandValue)> = None;
+    for_each_combination(cards, 5, |hand| {
+        let value = evaluate_short_deck_hand(hand).expect("5장이고 중복은 위에서 걸렀다");
+        let better = best
+            .as_ref()
+            .is_none_or(|(_, b)| Variant::ShortDeck.compare_hands(&value, b) == Ordering::Greater);
+        if better {
+            best = Some((hand.to_vec(), value));
+        }
+    });
+    Ok(best.expect("5장 이상이면 조합이 있다"))
+}
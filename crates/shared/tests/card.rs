@@ -0,0 +1,82 @@
+use holdem_shared::*;
+
+#[test]
+fn display_symbol_and_ascii_forms() {
+    let ten = Card::new(Rank::Ten, Suit::Hearts);
+    assert_eq!(ten.to_string(), "10♥");
+    assert_eq!(format!("{:#}", ten), "Th");
+    assert_eq!(ten.code(), "Th");
+    assert_eq!(Card::new(Rank::Ace, Suit::Spades).code(), "As");
+}
+
+#[test]
+fn parse_single_cards() {
+    let ace = Card::new(Rank::Ace, Suit::Spades);
+    assert_eq!("As".parse::<Card>(), Ok(ace));
+    assert_eq!("as".parse::<Card>(), Ok(ace));
+    assert_eq!("A♠".parse::<Card>(), Ok(ace));
+
+    let ten = Card::new(Rank::Ten, Suit::Diamonds);
+    assert_eq!("Td".parse::<Card>(), Ok(ten));
+    assert_eq!("10d".parse::<Card>(), Ok(ten));
+    assert_eq!("10♦".parse::<Card>(), Ok(ten));
+
+    assert_eq!("10".parse::<Rank>(), Ok(Rank::Ten));
+    assert_eq!("q".parse::<Rank>(), Ok(Rank::Queen));
+    assert_eq!("♣".parse::<Suit>(), Ok(Suit::Clubs));
+
+    for bad in ["", "A", "Ax", "1s", "AsK", "AsKd", "11h"] {
+        assert!(bad.parse::<Card>().is_err(), "{bad}");
+    }
+}
+
+#[test]
+fn display_round_trips_for_whole_deck() {
+    for card in Deck::new().cards {
+        assert_eq!(card.to_string().parse::<Card>(), Ok(card));
+        assert_eq!(card.code().parse::<Card>(), Ok(card));
+    }
+}
+
+#[test]
+fn parse_boards() {
+    let expected = vec![
+        Card::new(Rank::Ace, Suit::Hearts),
+        Card::new(Rank::King, Suit::Diamonds),
+        Card::new(Rank::Ten, Suit::Clubs),
+    ];
+    assert_eq!(parse_cards("AhKdTc"), Ok(expected.clone()));
+    assert_eq!(parse_cards("A♥ K♦ 10♣"), Ok(expected.clone()));
+    assert_eq!(parse_cards("Ah, Kd, 10c"), Ok(expected));
+    assert_eq!(parse_cards(""), Ok(vec![]));
+    assert!(parse_cards("AhKd7").is_err());
+}
+
+#[test]
+fn index_is_compact_and_unique() {
+    let mut seen = CardSet::new();
+    for card in Deck::new().cards {
+        let index = card.index();
+        assert!(index < 52);
+        assert_eq!(Card::from_index(index), Some(card));
+        assert!(!seen.contains(card));
+        seen.insert(card);
+    }
+    assert_eq!(seen.len(), 52);
+    assert_eq!(Card::from_index(52), None);
+    assert_eq!(Card::new(Rank::Two, Suit::Hearts).index(), 0);
+    assert_eq!(Card::new(Rank::Ace, Suit::Spades).index(), 51);
+}
+
+#[test]
+fn card_set_operations() {
+    let board = parse_cards("AhKd7c").unwrap();
+    let mut set: CardSet = board.iter().copied().collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(board[1]));
+
+    set.remove(board[1]);
+    assert!(!set.contains(board[1]));
+    assert_eq!(set.iter().collect::<Vec<_>>(), parse_cards("7cAh").unwrap());
+    assert!(CardSet::new().is_empty());
+}
@@ -0,0 +1,79 @@
+use holdem_shared::combinatorics::*;
+use holdem_shared::*;
+
+#[test]
+fn counting_formulas() {
+    assert_eq!(combinations(52, 5), 2_598_960);
+    assert_eq!(combinations(40, 2), 780);
+    assert_eq!(combinations(10, 0), 1);
+    assert_eq!(combinations(3, 5), 0);
+    // 중간값이 u64를 넘어도 결과가 맞아야 한다
+    assert_eq!(combinations(66, 33), 7_219_428_434_016_265_740);
+    assert_eq!(combinations(100, 50), u64::MAX);
+
+    assert_eq!(permutations(10, 2), 90);
+    assert_eq!(permutations(5, 0), 1);
+    assert_eq!(permutations(2, 3), 0);
+
+    assert_eq!(multichoose(10, 2), 55);
+    assert_eq!(multichoose(3, 2), 6);
+    assert_eq!(multichoose(0, 0), 1);
+    assert_eq!(repeated_permutations(10, 3), 1000);
+    assert_eq!(multiset_permutations(&[3, 1, 1]), 20);
+    assert_eq!(multiset_permutations(&[2, 2, 2]), 90);
+    assert_eq!(multiset_permutations(&[4; 13]), u64::MAX);
+}
+
+#[test]
+fn enumerator_visits_every_combination_once() {
+    let deck = Deck::new().cards;
+    let mut seen = std::collections::HashSet::new();
+    for_each_combination(&deck[..10], 3, |hand| {
+        assert!(seen.insert(hand.to_vec()));
+    });
+    assert_eq!(seen.len() as u64, combinations(10, 3));
+
+    let mut count = 0;
+    for_each_combination(&deck[..2], 3, |_| count += 1);
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn probability_reduces_fractions() {
+    let p = Probability::new(60, 780);
+    assert_eq!(p.reduced(), (1, 13));
+    assert_eq!(p.to_string(), "1/13 (7.69%)");
+    assert_eq!(Probability::new(0, 0).reduced(), (0, 1));
+}
+
+/// docs/README.md의 40장 덱 2장 확률
+#[test]
+fn forty_card_two_card_odds_match_readme() {
+    let deck = Variant::OneToTen.deck_from(Deck::new()).cards;
+    let pair = event_probability(&deck, 2, |h| h[0].rank == h[1].rank);
+    assert_eq!(pair.reduced(), (1, 13));
+    let suited = event_probability(&deck, 2, |h| h[0].suit == h[1].suit);
+    assert_eq!(suited.reduced(), (3, 13));
+}
+
+/// 1~10 하우스 게임 3장 족보 확률 (README2의 1/12, 1/15, 3/4와 다르다)
+#[test]
+fn forty_card_three_card_categories() {
+    let deck = Variant::OneToTen.deck_from(Deck::new()).cards;
+    let odds = category_odds(&deck, 3, |h| {
+        best_one_to_ten_hand(&h[..1], &h[1..]).unwrap().1.rank
+    });
+    let count = |rank: HandRank| {
+        odds.iter()
+            .find(|o| o.rank == rank)
+            .map(|o| o.probability.hits)
+            .unwrap_or(0)
+    };
+
+    assert!(odds.iter().all(|o| o.probability.total == 9880));
+    assert_eq!(count(HandRank::ThreeOfAKind), 40);
+    assert_eq!(count(HandRank::Straight), 512);
+    assert_eq!(count(HandRank::OnePair), 2160);
+    assert_eq!(count(HandRank::HighCard), 7168);
+    assert_eq!(odds.len(), 4);
+}
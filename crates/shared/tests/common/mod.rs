@@ -0,0 +1,35 @@
+//! 여러 통합 테스트가 함께 쓰는 테이블/카드 준비 함수
+#![allow(dead_code)]
+
+use holdem_shared::*;
+
+pub fn cards(text: &str) -> Vec<Card> {
+    parse_cards(text).unwrap()
+}
+
+/// `p0`, `p1`, ... 순서로 주어진 칩을 가진 플레이어를 앉힌다.
+pub fn seat(table: &mut Table, chips: &[i32]) {
+    for (i, &chips) in chips.iter().enumerate() {
+        table
+            .seat_player(Player::new(format!("p{i}"), format!("플레이어{i}"), chips))
+            .unwrap();
+    }
+}
+
+/// 기본 설정 테이블에 플레이어를 앉힌다.
+pub fn table_with(chips: &[i32]) -> Table {
+    let mut table = Table::new();
+    seat(&mut table, chips);
+    table
+}
+
+/// 뽑히는 순서대로 적은 카드로 덱을 만든다 (딜은 덱 맨 뒤부터)
+pub fn stacked_deck(draws: &[Card]) -> Deck {
+    let mut rest: Vec<Card> = Deck::new()
+        .cards
+        .into_iter()
+        .filter(|c| !draws.contains(c))
+        .collect();
+    rest.extend(draws.iter().rev());
+    Deck { cards: rest }
+}
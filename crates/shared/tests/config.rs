@@ -0,0 +1,244 @@
+mod common;
+
+use common::{cards, seat, stacked_deck};
+use holdem_shared::*;
+
+#[test]
+fn config_validation() {
+    assert_eq!(TableConfig::default().validate(), Ok(()));
+    for variant in Variant::ALL {
+        assert_eq!(TableConfig::for_variant(variant).validate(), Ok(()));
+    }
+
+    let invalid = |config: TableConfig| config.validate().unwrap_err();
+    let base = TableConfig::default();
+    assert_eq!(
+        invalid(TableConfig { small_blind: 20, ..base.clone() }),
+        ConfigError::InvalidBlinds
+    );
+    assert_eq!(
+        invalid(TableConfig { small_blind: 0, ..base.clone() }),
+        ConfigError::InvalidBlinds
+    );
+    assert_eq!(
+        invalid(TableConfig { ante: Ante::Everyone(0), ..base.clone() }),
+        ConfigError::InvalidAnte
+    );
+    assert_eq!(
+        invalid(TableConfig { min_buy_in: 3000, ..base.clone() }),
+        ConfigError::InvalidBuyInRange
+    );
+    assert_eq!(
+        invalid(TableConfig { starting_stack: 5000, ..base.clone() }),
+        ConfigError::StartingStackOutOfRange
+    );
+    assert_eq!(
+        invalid(TableConfig { variant: Variant::OneToTen, ..base.clone() }),
+        ConfigError::InvalidSeats { max: 4 }
+    );
+    assert_eq!(
+        Table::with_config(TableConfig { seats: 1, ..base }).err(),
+        Some(TableError::InvalidConfig(ConfigError::InvalidSeats { max: 10 }))
+    );
+}
+
+#[test]
+fn stakes_label() {
+    let config = TableConfig {
+        small_blind: 10,
+        big_blind: 20,
+        ante: Ante::BigBlind(20),
+        ..TableConfig::default()
+    };
+    assert_eq!(config.stakes(), "10/20 (BB 앤티 20)");
+    assert_eq!(TableConfig::default().stakes(), "5/10");
+}
+
+#[test]
+fn antes_are_posted_before_blinds() {
+    let mut table = Table::with_config(TableConfig {
+        small_blind: 10,
+        big_blind: 20,
+        ante: Ante::Everyone(5),
+        ..TableConfig::default()
+    })
+    .unwrap();
+    seat(&mut table, &[1000, 1000, 1000]);
+
+    let events = table.start_hand(Deck::new()).unwrap();
+    let posted: Vec<&TableEvent> = events
+        .iter()
+        .filter(|e| matches!(e, TableEvent::AntePosted { .. } | TableEvent::BlindPosted { .. }))
+        .collect();
+    assert_eq!(posted.len(), 5);
+    assert!(posted[..3].iter().all(|e| matches!(e, TableEvent::AntePosted { amount: 5, .. })));
+    assert_eq!(
+        posted[4],
+        &TableEvent::BlindPosted { player_id: "p2".into(), amount: 20 }
+    );
+
+    let state = table.state();
+    assert_eq!(state.pot, 45);
+    assert_eq!(state.current_bet, 20);
+    assert_eq!(state.min_raise, 20);
+    // 앤티는 팟에만 들어가고 베팅에는 치지 않는다
+    assert_eq!(state.players[0].bet, 0);
+    assert_eq!(state.players[0].total_bet, 0);
+    assert_eq!(state.players[0].ante, 5);
+
+    // 레이즈는 빅 블라인드만큼은 올려야 한다
+    assert_eq!(
+        table.apply("p0", Action::Raise { amount: 10 }),
+        Err(TableError::RaiseTooSmall { min_raise: 20 })
+    );
+    table.apply("p0", Action::Fold).unwrap();
+    let events = table.apply("p1", Action::Fold).unwrap();
+    let Some(TableEvent::HandEnded { winners, .. }) = events.last() else {
+        panic!("핸드가 끝나야 합니다: {:?}", events);
+    };
+    assert_eq!(winners, &vec![Payout { player_id: "p2".into(), amount: 45 }]);
+}
+
+#[test]
+fn big_blind_ante_can_put_short_stack_all_in() {
+    let mut table = Table::with_config(TableConfig {
+        ante: Ante::BigBlind(30),
+        min_buy_in: 20,
+        ..TableConfig::default()
+    })
+    .unwrap();
+    seat(&mut table, &[1000, 1000, 25]);
+
+    table.start_hand(Deck::new()).unwrap();
+    let state = table.state();
+    let big_blind = &state.players[2];
+    assert!(big_blind.all_in);
+    assert_eq!(big_blind.chips, 0);
+    assert_eq!(big_blind.bet, 0);
+    assert_eq!(big_blind.total_bet, 0);
+    assert_eq!(big_blind.ante, 25);
+    assert_eq!(state.pot, 30);
+
+    // 앤티만 낸 빅 블라인드는 앤티 팟만 가져갈 수 있다
+    table.apply("p0", Action::Call).unwrap();
+    table.apply("p1", Action::Call).unwrap();
+    let pots = build_pots(&table.state().players);
+    assert_eq!(pots.len(), 2);
+    assert_eq!(pots[0].amount, 25);
+    assert_eq!(pots[0].eligible.len(), 3);
+    assert_eq!(pots[1].amount, 20);
+    assert_eq!(pots[1].eligible, vec!["p0".to_string(), "p1".to_string()]);
+}
+
+#[test]
+fn big_blind_ante_is_dead_money_in_the_main_pot() {
+    let mut table = Table::with_config(TableConfig {
+        ante: Ante::BigBlind(10),
+        ..TableConfig::default()
+    })
+    .unwrap();
+    seat(&mut table, &[1000, 1000, 1000]);
+
+    // 홀카드는 p1, p2, p0 순서로 두 바퀴, 이후 번 + 플랍, 번 + 턴, 번 + 리버
+    let draws = cards("7cKhAh2dKdAd 3s9c8s4h 5cJd 6cQs");
+    table.start_hand(stacked_deck(&draws)).unwrap();
+    table.apply("p0", Action::Call).unwrap();
+    table.apply("p1", Action::Call).unwrap();
+    table.apply("p2", Action::Check).unwrap();
+
+    // 앤티는 사이드 팟을 만들지 않는다
+    let pots = build_pots(&table.state().players);
+    assert_eq!(pots, vec![Pot { amount: 40, eligible: vec!["p0".into(), "p1".into(), "p2".into()] }]);
+
+    let mut events = Vec::new();
+    for _ in 0..3 {
+        for player in ["p1", "p2", "p0"] {
+            events = table.apply(player, Action::Check).unwrap();
+        }
+    }
+    let Some(TableEvent::HandEnded { winners, .. }) = events.last() else {
+        panic!("핸드가 끝나야 합니다: {:?}", events);
+    };
+    // 빅 블라인드가 지면 앤티를 돌려받지 못한다
+    assert_eq!(winners, &vec![Payout { player_id: "p0".into(), amount: 40 }]);
+    let chips: Vec<i32> = table.state().players.iter().map(|p| p.chips).collect();
+    assert_eq!(chips, vec![1030, 990, 980]);
+}
+
+#[test]
+fn partial_ante_all_in_wins_only_matching_antes() {
+    let mut table = Table::with_config(TableConfig {
+        ante: Ante::Everyone(5),
+        min_buy_in: 1,
+        ..TableConfig::default()
+    })
+    .unwrap();
+    seat(&mut table, &[3, 1000, 1000]);
+
+    // 딜러 p0는 앤티 5 중 3만 내고 올인. 나머지 카드는 위 테스트와 같다
+    let draws = cards("7cKhAh2dKdAd 3s9c8s4h 5cJd 6cQs");
+    table.start_hand(stacked_deck(&draws)).unwrap();
+    assert_eq!(table.state().players[0].ante, 3);
+    assert!(table.state().players[0].all_in);
+    table.apply("p1", Action::Call).unwrap();
+    table.apply("p2", Action::Check).unwrap();
+
+    // p0는 각자에게서 3씩만 가져갈 수 있다. 남은 앤티 2씩은 메인 팟에 합쳐진다
+    let pots = build_pots(&table.state().players);
+    assert_eq!(
+        pots,
+        vec![
+            Pot { amount: 9, eligible: vec!["p0".into(), "p1".into(), "p2".into()] },
+            Pot { amount: 24, eligible: vec!["p1".into(), "p2".into()] },
+        ]
+    );
+
+    let mut events = Vec::new();
+    for _ in 0..3 {
+        for player in ["p1", "p2"] {
+            events = table.apply(player, Action::Check).unwrap();
+        }
+    }
+    let Some(TableEvent::HandEnded { winners, .. }) = events.last() else {
+        panic!("핸드가 끝나야 합니다: {:?}", events);
+    };
+    // 에이스 포켓인 p0가 앤티 팟, 킹 포켓인 p2가 나머지를 가져간다
+    assert_eq!(
+        winners,
+        &vec![
+            Payout { player_id: "p0".into(), amount: 9 },
+            Payout { player_id: "p2".into(), amount: 24 },
+        ]
+    );
+    let chips: Vec<i32> = table.state().players.iter().map(|p| p.chips).collect();
+    assert_eq!(chips, vec![9, 985, 1009]);
+}
+
+#[test]
+fn seats_and_buy_ins_are_enforced() {
+    let mut table = Table::with_config(TableConfig {
+        seats: 2,
+        ..TableConfig::default()
+    })
+    .unwrap();
+    assert_eq!(
+        table.seat_player(Player::new("p0".into(), "적음".into(), 100)),
+        Err(TableError::InvalidBuyIn { min: 200, max: 2000 })
+    );
+    seat(&mut table, &[200, 2000]);
+    assert_eq!(
+        table.seat_player(Player::new("p2".into(), "셋째".into(), 1000)),
+        Err(TableError::TableFull { max_players: 2 })
+    );
+
+    // 앉은 인원보다 좌석을 줄일 수 없고, 게임 종류를 바꾸면 좌석은 그 최대 인원으로 줄어든다
+    let mut table = Table::new();
+    seat(&mut table, &[1000; 5]);
+    assert_eq!(
+        table.set_config(TableConfig { seats: 4, ..TableConfig::default() }),
+        Err(TableError::TableFull { max_players: 4 })
+    );
+    table.remove_player("p4").unwrap();
+    assert_eq!(table.set_variant(Variant::OneToTen), Ok(()));
+    assert_eq!(table.config().seats, 4);
+}
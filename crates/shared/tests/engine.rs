@@ -0,0 +1,181 @@
+mod common;
+
+use common::{cards, stacked_deck, table_with};
+use holdem_shared::*;
+
+fn blinds(events: &[TableEvent]) -> Vec<(String, i32)> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            TableEvent::BlindPosted { player_id, amount } => Some((player_id.clone(), *amount)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn current_player(table: &Table) -> &str {
+    let state = table.state();
+    &state.players[state.current_player_idx].id
+}
+
+#[test]
+fn heads_up_button_posts_small_blind_and_acts_first_preflop() {
+    let mut table = table_with(&[1000, 1000]);
+
+    let events = table.start_hand(Deck::new()).unwrap();
+    assert_eq!(blinds(&events), vec![("p0".into(), 5), ("p1".into(), 10)]);
+    assert_eq!(current_player(&table), "p0");
+
+    table.apply("p0", Action::Call).unwrap();
+    table.apply("p1", Action::Check).unwrap();
+    assert_eq!(table.state().phase, GamePhase::Flop);
+    // 플랍부터는 버튼이 마지막이다
+    assert_eq!(current_player(&table), "p1");
+    assert_eq!(table.apply("p0", Action::Check), Err(TableError::NotYourTurn));
+    table.apply("p1", Action::Check).unwrap();
+    table.apply("p0", Action::Check).unwrap();
+    assert_eq!(table.state().phase, GamePhase::Turn);
+    assert_eq!(current_player(&table), "p1");
+    table.apply("p1", Action::Fold).unwrap();
+
+    // 다음 핸드는 버튼이 넘어간다
+    let events = table.start_hand(Deck::new()).unwrap();
+    assert_eq!(blinds(&events), vec![("p1".into(), 5), ("p0".into(), 10)]);
+    assert_eq!(current_player(&table), "p1");
+}
+
+#[test]
+fn three_handed_blinds_sit_left_of_the_button() {
+    let mut table = table_with(&[1000, 1000, 1000]);
+    let events = table.start_hand(Deck::new()).unwrap();
+    assert_eq!(blinds(&events), vec![("p1".into(), 5), ("p2".into(), 10)]);
+    assert_eq!(current_player(&table), "p0");
+}
+
+#[test]
+fn later_streets_skip_folded_and_all_in_players() {
+    let mut table = table_with(&[1000, 1000, 1000, 300]);
+    table.start_hand(Deck::new()).unwrap();
+
+    // 버튼 p0, 스몰 p1, 빅 p2, UTG p3
+    table.apply("p3", Action::Raise { amount: 290 }).unwrap();
+    assert!(table.state().players[3].all_in);
+    table.apply("p0", Action::Call).unwrap();
+    table.apply("p1", Action::Fold).unwrap();
+    table.apply("p2", Action::Call).unwrap();
+
+    // 스몰 블라인드는 폴드, p3는 올인이라 플랍은 빅 블라인드부터 시작해 버튼으로 끝난다
+    assert_eq!(table.state().phase, GamePhase::Flop);
+    assert_eq!(current_player(&table), "p2");
+    table.apply("p2", Action::Check).unwrap();
+    assert_eq!(current_player(&table), "p0");
+    table.apply("p0", Action::Check).unwrap();
+    assert_eq!(table.state().phase, GamePhase::Turn);
+    assert_eq!(current_player(&table), "p2");
+}
+
+#[test]
+fn players_cannot_sit_twice() {
+    let mut table = table_with(&[1000]);
+    assert_eq!(
+        table.seat_player(Player::new("p0".into(), "또".into(), 1000)),
+        Err(TableError::AlreadySeated)
+    );
+}
+
+#[test]
+fn players_leave_only_between_hands() {
+    let mut table = table_with(&[1000, 1000, 1000]);
+    table.start_hand(Deck::new()).unwrap();
+    assert_eq!(table.remove_player("p1"), Err(TableError::HandInProgress));
+
+    table.apply("p0", Action::Fold).unwrap();
+    table.apply("p1", Action::Fold).unwrap();
+    assert_eq!(table.remove_player("p1"), Ok(()));
+    assert_eq!(table.state().players.len(), 2);
+}
+
+#[test]
+fn disconnected_players_check_or_fold() {
+    let mut table = table_with(&[1000, 1000, 1000]);
+    assert!(table.set_connected("p0", false));
+    assert!(!table.set_connected("없음", false));
+    assert!(!table.state().players[0].is_active);
+    assert_eq!(table.auto_action("p0"), None);
+
+    table.start_hand(Deck::new()).unwrap();
+    // 빅 블라인드를 맞춰야 하니 폴드
+    assert_eq!(table.auto_action("p0"), Some(Action::Fold));
+    assert_eq!(table.auto_action("p1"), None);
+    table.apply("p0", Action::Call).unwrap();
+    table.apply("p1", Action::Call).unwrap();
+    // 빅 블라인드는 더 낼 것이 없으니 체크
+    assert_eq!(table.auto_action("p2"), Some(Action::Check));
+}
+
+#[test]
+fn huge_raise_goes_all_in() {
+    let mut table = table_with(&[1000, 500]);
+    table.start_hand(Deck::new()).unwrap();
+
+    let events = table.apply("p0", Action::Raise { amount: i32::MAX }).unwrap();
+    assert_eq!(
+        events[0],
+        TableEvent::PlayerActed {
+            player_id: "p0".into(),
+            action: Action::Raise { amount: i32::MAX },
+            paid: 995,
+            all_in: true,
+        }
+    );
+    assert_eq!(table.state().current_bet, 1000);
+    assert_eq!(table.state().pot, 1010);
+
+    let events = table.apply("p1", Action::Raise { amount: i32::MAX }).unwrap();
+    assert!(matches!(events[0], TableEvent::PlayerActed { paid: 490, all_in: true, .. }));
+    assert!(matches!(events.last(), Some(TableEvent::HandEnded { .. })));
+}
+
+#[test]
+fn button_and_cards_skip_busted_players() {
+    let mut table = table_with(&[1000, 200, 1000]);
+
+    // 홀카드는 p1, p2, p0 순서로 두 바퀴. p0의 에이스 페어가 p1을 잡는다
+    let draws = cards("7cKhAh2dKdAd 3s9c8s4h 5cJd 6cQs");
+    table.start_hand(stacked_deck(&draws)).unwrap();
+    table.apply("p0", Action::Raise { amount: 190 }).unwrap();
+    table.apply("p1", Action::Call).unwrap();
+    let events = table.apply("p2", Action::Fold).unwrap();
+    assert!(matches!(events.last(), Some(TableEvent::HandEnded { .. })));
+    assert_eq!(table.state().players[1].chips, 0);
+
+    // 버튼은 칩이 없는 p1을 건너뛰고, 둘만 남았으니 버튼이 스몰 블라인드를 낸다
+    let events = table.start_hand(Deck::new()).unwrap();
+    assert_eq!(events[0], TableEvent::HandStarted { dealer_idx: 2 });
+    assert_eq!(blinds(&events), vec![("p2".into(), 5), ("p0".into(), 10)]);
+    assert_eq!(current_player(&table), "p2");
+
+    // 빠진 자리에는 카드를 주지 않는다
+    assert!(table.state().players[1].hand.is_empty());
+    assert!(!events.iter().any(|e| matches!(
+        e,
+        TableEvent::HoleCardsDealt { player_id, .. } if player_id == "p1"
+    )));
+    assert_eq!(table.deal_log().len(), 4);
+}
+
+#[test]
+fn one_funded_player_cannot_start_a_hand() {
+    let mut table = table_with(&[1000, 200]);
+
+    // 홀카드는 p1, p0 순서로 두 바퀴
+    let draws = cards("7cAh2dAd 3s9c8s4h 5cJd 6cQs");
+    table.start_hand(stacked_deck(&draws)).unwrap();
+    table.apply("p0", Action::Raise { amount: 190 }).unwrap();
+    let events = table.apply("p1", Action::Call).unwrap();
+    assert!(matches!(events.last(), Some(TableEvent::HandEnded { .. })));
+    assert_eq!(table.state().players[1].chips, 0);
+
+    // 두 명이 앉아 있어도 칩이 남은 플레이어는 한 명뿐이다
+    assert_eq!(table.start_hand(Deck::new()), Err(TableError::NotEnoughPlayers));
+}
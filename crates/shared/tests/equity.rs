@@ -0,0 +1,120 @@
+use holdem_shared::*;
+
+fn card(rank: Rank, suit: Suit) -> Card {
+    Card::new(rank, suit)
+}
+
+#[test]
+fn aces_against_kings_preflop() {
+    let hands = vec![
+        vec![card(Rank::Ace, Suit::Spades), card(Rank::Ace, Suit::Hearts)],
+        vec![card(Rank::King, Suit::Clubs), card(Rank::King, Suit::Diamonds)],
+    ];
+    let report = calculate_equity(&hands, &[], &[], EquityOptions::default()).unwrap();
+
+    // C(48,5) 보드를 전부 계산
+    assert!(report.exhaustive);
+    assert_eq!(report.boards, 1_712_304);
+    // 알려진 값: 81.06% / 0.38% / 18.55%
+    assert!((report.players[0].win - 81.06).abs() < 0.01);
+    assert!((report.players[0].tie - 0.38).abs() < 0.01);
+    assert!((report.players[1].win - 18.55).abs() < 0.01);
+    assert!((report.players[0].equity + report.players[1].equity - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn complete_board_is_decided() {
+    let hands = vec![
+        vec![card(Rank::Ace, Suit::Spades), card(Rank::Two, Suit::Hearts)],
+        vec![card(Rank::King, Suit::Clubs), card(Rank::Three, Suit::Diamonds)],
+    ];
+    let board = [
+        card(Rank::Ace, Suit::Clubs),
+        card(Rank::Seven, Suit::Diamonds),
+        card(Rank::Eight, Suit::Hearts),
+        card(Rank::Nine, Suit::Spades),
+        card(Rank::Jack, Suit::Clubs),
+    ];
+    let report = calculate_equity(&hands, &board, &[], EquityOptions::default()).unwrap();
+    assert_eq!(report.boards, 1);
+    assert_eq!(report.players[0].win, 100.0);
+    assert_eq!(report.players[1].lose, 100.0);
+}
+
+#[test]
+fn board_plays_for_both_is_a_tie() {
+    let hands = vec![
+        vec![card(Rank::Two, Suit::Spades), card(Rank::Three, Suit::Hearts)],
+        vec![card(Rank::Two, Suit::Clubs), card(Rank::Three, Suit::Diamonds)],
+    ];
+    let board = [
+        card(Rank::Ten, Suit::Clubs),
+        card(Rank::Jack, Suit::Diamonds),
+        card(Rank::Queen, Suit::Hearts),
+        card(Rank::King, Suit::Spades),
+        card(Rank::Ace, Suit::Clubs),
+    ];
+    let report = calculate_equity(&hands, &board, &[], EquityOptions::default()).unwrap();
+    assert_eq!(report.players[0].tie, 100.0);
+    assert_eq!(report.players[1].equity, 50.0);
+}
+
+#[test]
+fn monte_carlo_is_reproducible_with_seed() {
+    let hands = vec![
+        vec![card(Rank::Ace, Suit::Spades), card(Rank::King, Suit::Spades)],
+        vec![card(Rank::Queen, Suit::Clubs), card(Rank::Queen, Suit::Diamonds)],
+        vec![card(Rank::Seven, Suit::Hearts), card(Rank::Six, Suit::Hearts)],
+    ];
+    let options = EquityOptions {
+        exhaustive_limit: 0,
+        samples: 20_000,
+        seed: 42,
+    };
+    let first = calculate_equity(&hands, &[], &[], options).unwrap();
+    let second = calculate_equity(&hands, &[], &[], options).unwrap();
+    assert!(!first.exhaustive);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn dead_and_duplicate_cards_are_checked() {
+    let ace = card(Rank::Ace, Suit::Spades);
+    let hands = vec![
+        vec![ace, card(Rank::King, Suit::Spades)],
+        vec![card(Rank::Queen, Suit::Clubs), card(Rank::Queen, Suit::Diamonds)],
+    ];
+    assert_eq!(
+        calculate_equity(&hands, &[], &[ace], EquityOptions::default()),
+        Err(EquityError::DuplicateCard(ace))
+    );
+    assert_eq!(
+        calculate_equity(&hands[..1], &[], &[], EquityOptions::default()),
+        Err(EquityError::NotEnoughPlayers)
+    );
+}
+
+#[test]
+fn complete_board_with_every_other_card_dead() {
+    let hands = vec![
+        vec![card(Rank::Ace, Suit::Spades), card(Rank::Two, Suit::Hearts)],
+        vec![card(Rank::King, Suit::Clubs), card(Rank::Three, Suit::Diamonds)],
+    ];
+    let board = [
+        card(Rank::Ace, Suit::Clubs),
+        card(Rank::Seven, Suit::Diamonds),
+        card(Rank::Eight, Suit::Hearts),
+        card(Rank::Nine, Suit::Spades),
+        card(Rank::Jack, Suit::Clubs),
+    ];
+    // 남은 43장을 모두 죽은 카드로 두면 덱이 빈다
+    let known: Vec<Card> = hands.iter().flatten().chain(&board).copied().collect();
+    let dead: Vec<Card> = Deck::new().cards.into_iter().filter(|c| !known.contains(c)).collect();
+    assert_eq!(dead.len(), 43);
+
+    for exhaustive_limit in [0, EquityOptions::default().exhaustive_limit] {
+        let options = EquityOptions { exhaustive_limit, ..EquityOptions::default() };
+        let report = calculate_equity(&hands, &board, &dead, options).unwrap();
+        assert_eq!(report.players[0].win, 100.0);
+    }
+}
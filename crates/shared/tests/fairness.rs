@@ -0,0 +1,105 @@
+mod common;
+
+use common::seat;
+use holdem_shared::fairness::{commitment_for, rebuild_deck};
+use holdem_shared::*;
+
+const SEED: [u8; 32] = [7; 32];
+
+/// 커밋된 덱으로 핸드 하나를 끝까지 (콜/체크) 진행하고 전체 딜 기록을 돌려준다
+fn play_hand(variant: Variant, entropy: &[String]) -> Vec<RevealedDeal> {
+    let mut table = Table::with_variant(variant);
+    seat(&mut table, &[1000; 3]);
+    table.start_hand(ShuffleCommit::from_seed(SEED).deck(entropy)).unwrap();
+    while table.state().is_betting() {
+        let state = table.state();
+        let player = &state.players[state.current_player_idx];
+        let action = if player.bet < state.current_bet { Action::Call } else { Action::Check };
+        let id = player.id.clone();
+        table.apply(&id, action).unwrap();
+    }
+    table
+        .deal_log()
+        .iter()
+        .enumerate()
+        .map(|(index, dealt)| RevealedDeal {
+            index,
+            dealt: dealt.clone(),
+        })
+        .collect()
+}
+
+#[test]
+fn commitment_is_fixed_by_the_seed() {
+    let commit = ShuffleCommit::from_seed(SEED);
+    assert_eq!(commit.commitment(), commitment_for(&SEED));
+    assert_eq!(commit.commitment().len(), 64);
+    assert_ne!(commitment_for(&SEED), commitment_for(&[8; 32]));
+    // 엔트로피가 다르면 덱 순서도 다르다
+    assert_eq!(rebuild_deck(&SEED, &[]).cards, commit.deck(&[]).cards);
+    assert_ne!(rebuild_deck(&SEED, &[]).cards, rebuild_deck(&SEED, &["a".into()]).cards);
+}
+
+#[test]
+fn honest_hand_verifies_in_every_variant() {
+    let entropy = vec!["내 엔트로피".to_string()];
+    let commit = ShuffleCommit::from_seed(SEED);
+    for variant in Variant::ALL {
+        let log = play_hand(variant, &entropy);
+        let deck = verify_hand(variant, &commit.commitment(), &commit.reveal(), &entropy, &log);
+        assert!(deck.is_ok(), "{variant}: {:?}", deck);
+    }
+}
+
+#[test]
+fn wrong_seed_is_rejected() {
+    let entropy = Vec::new();
+    let log = play_hand(Variant::Holdem, &entropy);
+    let commitment = ShuffleCommit::from_seed(SEED).commitment();
+    let other = ShuffleCommit::from_seed([8; 32]).reveal();
+    assert_eq!(
+        verify_hand(Variant::Holdem, &commitment, &other, &entropy, &log).unwrap_err(),
+        FairnessError::CommitmentMismatch
+    );
+    assert_eq!(
+        verify_hand(Variant::Holdem, &commitment, "zz", &entropy, &log).unwrap_err(),
+        FairnessError::InvalidSeed
+    );
+}
+
+#[test]
+fn tampered_entropy_or_deal_order_is_rejected() {
+    let entropy = vec!["a".to_string(), "b".to_string()];
+    let commit = ShuffleCommit::from_seed(SEED);
+    let log = play_hand(Variant::Holdem, &entropy);
+
+    // 서버가 엔트로피를 빼거나 바꾸면 덱이 달라진다
+    let dropped = vec!["a".to_string()];
+    let verified = verify_hand(Variant::Holdem, &commit.commitment(), &commit.reveal(), &dropped, &log);
+    assert!(matches!(verified, Err(FairnessError::DealMismatch { .. })));
+
+    // 카드 두 장의 순서를 바꾸면 앞의 것부터 어긋난다
+    let mut swapped = log.clone();
+    let (first, second) = (swapped[1].dealt.card, swapped[4].dealt.card);
+    swapped[1].dealt.card = second;
+    swapped[4].dealt.card = first;
+    assert_eq!(
+        verify_hand(Variant::Holdem, &commit.commitment(), &commit.reveal(), &entropy, &swapped)
+            .unwrap_err(),
+        FairnessError::DealMismatch { index: 1 }
+    );
+}
+
+#[test]
+fn visible_deals_hide_unshown_hole_cards() {
+    let log: Vec<DealtCard> = play_hand(Variant::Holdem, &[]).into_iter().map(|d| d.dealt).collect();
+    let visible = visible_deals(&log, "p0", &["p1".to_string()]);
+    assert!(visible.iter().all(|d| d.dealt.target != DealTarget::Player("p2".into())));
+    assert_eq!(visible.len(), log.len() - 2);
+    // 남은 카드는 원래 위치를 그대로 적는다
+    assert!(visible.iter().all(|d| log[d.index] == d.dealt));
+
+    let commit = ShuffleCommit::from_seed(SEED);
+    let verified = verify_hand(Variant::Holdem, &commit.commitment(), &commit.reveal(), &[], &visible);
+    assert!(verified.is_ok());
+}
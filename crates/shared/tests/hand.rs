@@ -0,0 +1,196 @@
+use holdem_shared::*;
+use std::collections::HashMap;
+
+fn card(rank: Rank, suit: Suit) -> Card {
+    Card::new(rank, suit)
+}
+
+/// 52장에서 나올 수 있는 모든 5장(2,598,960개)의 족보별 개수
+#[test]
+fn five_card_category_counts() {
+    let cards = Deck::new().cards;
+    let mut counts: HashMap<HandRank, usize> = HashMap::new();
+    for a in 0..52 {
+        for b in a + 1..52 {
+            for c in b + 1..52 {
+                for d in c + 1..52 {
+                    for e in d + 1..52 {
+                        let hand = [cards[a], cards[b], cards[c], cards[d], cards[e]];
+                        let value = evaluate_hand(&hand).unwrap();
+                        *counts.entry(value.rank).or_default() += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let expected = [
+        (HandRank::HighCard, 1_302_540),
+        (HandRank::OnePair, 1_098_240),
+        (HandRank::TwoPair, 123_552),
+        (HandRank::ThreeOfAKind, 54_912),
+        (HandRank::Straight, 10_200),
+        (HandRank::Flush, 5_108),
+        (HandRank::FullHouse, 3_744),
+        (HandRank::FourOfAKind, 624),
+        (HandRank::StraightFlush, 36),
+        (HandRank::RoyalFlush, 4),
+    ];
+    for (rank, count) in expected {
+        assert_eq!(counts.get(&rank).copied().unwrap_or(0), count, "{:?}", rank);
+    }
+}
+
+#[test]
+fn wheel_is_five_high_straight() {
+    let wheel = [
+        card(Rank::Ace, Suit::Hearts),
+        card(Rank::Two, Suit::Clubs),
+        card(Rank::Three, Suit::Diamonds),
+        card(Rank::Four, Suit::Spades),
+        card(Rank::Five, Suit::Hearts),
+    ];
+    let six_high = [
+        card(Rank::Six, Suit::Hearts),
+        card(Rank::Two, Suit::Clubs),
+        card(Rank::Three, Suit::Diamonds),
+        card(Rank::Four, Suit::Spades),
+        card(Rank::Five, Suit::Hearts),
+    ];
+
+    let wheel_value = evaluate_hand(&wheel).unwrap();
+    assert_eq!(wheel_value.rank, HandRank::Straight);
+    assert_eq!(wheel_value.values, vec![5]);
+    assert!(evaluate_hand(&six_high).unwrap() > wheel_value);
+}
+
+#[test]
+fn steel_wheel_is_straight_flush_not_royal() {
+    let steel_wheel = [
+        card(Rank::Ace, Suit::Spades),
+        card(Rank::Two, Suit::Spades),
+        card(Rank::Three, Suit::Spades),
+        card(Rank::Four, Suit::Spades),
+        card(Rank::Five, Suit::Spades),
+    ];
+    let value = evaluate_hand(&steel_wheel).unwrap();
+    assert_eq!(value.rank, HandRank::StraightFlush);
+    assert_eq!(value.values, vec![5]);
+}
+
+#[test]
+fn best_hand_prefers_six_high_over_wheel() {
+    let cards = [
+        card(Rank::Ace, Suit::Hearts),
+        card(Rank::Two, Suit::Clubs),
+        card(Rank::Three, Suit::Diamonds),
+        card(Rank::Four, Suit::Spades),
+        card(Rank::Five, Suit::Hearts),
+        card(Rank::Six, Suit::Clubs),
+        card(Rank::King, Suit::Diamonds),
+    ];
+    let (_, value) = find_best_hand(&cards).unwrap();
+    assert_eq!(value.rank, HandRank::Straight);
+    assert_eq!(value.values, vec![6]);
+}
+
+#[test]
+fn wrong_card_counts_are_errors() {
+    let four = [
+        card(Rank::Ace, Suit::Hearts),
+        card(Rank::Ace, Suit::Clubs),
+        card(Rank::Ace, Suit::Diamonds),
+        card(Rank::Ace, Suit::Spades),
+    ];
+    assert_eq!(
+        evaluate_hand(&four),
+        Err(HandError::WrongCardCount { expected: 5, got: 4 })
+    );
+    assert_eq!(
+        evaluate_hand(&four[..1]),
+        Err(HandError::WrongCardCount { expected: 5, got: 1 })
+    );
+    assert_eq!(evaluate_hand(&[]), Err(HandError::WrongCardCount { expected: 5, got: 0 }));
+    assert_eq!(
+        find_best_hand(&four),
+        Err(HandError::WrongCardCount { expected: 5, got: 4 })
+    );
+}
+
+#[test]
+fn duplicate_cards_are_errors() {
+    let ace = card(Rank::Ace, Suit::Hearts);
+    let hand = [
+        ace,
+        ace,
+        card(Rank::King, Suit::Clubs),
+        card(Rank::Queen, Suit::Clubs),
+        card(Rank::Jack, Suit::Clubs),
+    ];
+    assert_eq!(evaluate_hand(&hand), Err(HandError::DuplicateCard(ace)));
+    assert_eq!(find_best_hand(&hand), Err(HandError::DuplicateCard(ace)));
+}
+
+fn value_of(text: &str) -> HandValue {
+    find_best_hand(&parse_cards(text).unwrap()).unwrap().1
+}
+
+#[test]
+fn english_descriptions() {
+    let cases = [
+        ("AhJd9c7s4h", "High Card, Ace-high, Jack-Nine-Seven-Four kickers"),
+        ("8h8dAcKs7h", "One Pair, Eights, Ace-King-Seven kickers"),
+        ("KhKd7c7sAh", "Two Pair, Kings and Sevens, Ace kicker"),
+        ("7h7d7cAsKh", "Three of a Kind, Sevens, Ace-King kickers"),
+        ("Ah2d3c4s5h", "Straight, Five-high"),
+        ("AhKh9h6h3h", "Flush, Ace-King-Nine-Six-Three"),
+        ("KhKdKc7s7h", "Full House, Kings full of Sevens"),
+        ("9h9d9c9sAh", "Four of a Kind, Nines, Ace kicker"),
+        ("5h6h7h8h9h", "Straight Flush, Nine-high"),
+        ("ThJhQhKhAh", "Royal Flush"),
+    ];
+    for (cards, expected) in cases {
+        assert_eq!(value_of(cards).describe(Language::English), expected);
+    }
+}
+
+#[test]
+fn korean_descriptions() {
+    assert_eq!(value_of("KhKd7c7sAh").to_string(), "투 페어, K 페어와 7 페어, A 키커");
+    assert_eq!(value_of("Ah2d3c4s5h").to_string(), "스트레이트, 5 하이");
+    assert_eq!(value_of("KhKdKc7s7h").to_string(), "풀 하우스, K 트리플에 7 페어");
+    assert_eq!(value_of("Th8d8c4s2h").to_string(), "원 페어, 8, 10-4-2 키커");
+}
+
+#[test]
+fn deciding_cards_stop_at_first_different_kicker() {
+    let board = parse_cards("KhKd7c3s2h").unwrap();
+    let hero = [parse_cards("AcQd").unwrap(), board.clone()].concat();
+    let villain = [parse_cards("AdJc").unwrap(), board.clone()].concat();
+    let (best, value) = find_best_hand(&hero).unwrap();
+    let (_, other) = find_best_hand(&villain).unwrap();
+
+    // 페어, 에이스 키커까지 같고 두 번째 키커(Q)에서 갈린다
+    let deciding = value.deciding_cards(&best, Some(&other));
+    assert_eq!(deciding, parse_cards("AcQdKhKd").unwrap());
+
+    // 비교 대상이 없으면 족보를 이루는 카드만
+    assert_eq!(value.deciding_cards(&best, None), parse_cards("KhKd").unwrap());
+
+    // 족보가 다르면 키커는 상관없다
+    let flush = value_of("2c5c8cJcQc");
+    assert_eq!(value.deciding_cards(&best, Some(&flush)), parse_cards("KhKd").unwrap());
+}
+
+#[test]
+fn deciding_cards_for_made_hands() {
+    let cards = parse_cards("Ah2d3c4s5hKs").unwrap();
+    let (best, value) = find_best_hand(&cards).unwrap();
+    assert_eq!(value.deciding_cards(&best, None).len(), 5);
+
+    let cards = parse_cards("9h9d9c9sAhKs").unwrap();
+    let (best, value) = find_best_hand(&cards).unwrap();
+    let other = value_of("9h9d9c9sKs");
+    assert_eq!(value.deciding_cards(&best, Some(&other)).len(), 5);
+    assert_eq!(value.deciding_cards(&best, None).len(), 4);
+}
@@ -0,0 +1,109 @@
+use holdem_shared::*;
+
+fn count(text: &str) -> usize {
+    Range::parse(text).unwrap().combos.len()
+}
+
+#[test]
+fn parses_standard_notation() {
+    assert_eq!(count("QQ"), 6);
+    assert_eq!(count("QQ+"), 18);
+    assert_eq!(count("22-55"), 24);
+    assert_eq!(count("AKs"), 4);
+    assert_eq!(count("AKo"), 12);
+    assert_eq!(count("AK"), 16);
+    assert_eq!(count("ATo+"), 48);
+    assert_eq!(count("A2s-A5s"), 16);
+    assert_eq!(count("76s-54s"), 12);
+    assert_eq!(count("AhKh"), 1);
+    assert_eq!(count("QQ+, AKs, ATo+, 76s-54s"), 18 + 4 + 48 + 12);
+    // 겹치는 항목은 한 번만
+    assert_eq!(count("AK, AKs"), 16);
+}
+
+#[test]
+fn weights_and_errors() {
+    let range = Range::parse("AA, KK:0.5").unwrap();
+    assert_eq!(range.weighted_count(), 6.0 + 3.0);
+
+    assert!(matches!(Range::parse("AXs"), Err(RangeError::InvalidToken(_))));
+    assert!(matches!(Range::parse("AAs"), Err(RangeError::InvalidToken(_))));
+    assert!(matches!(Range::parse("AK:2"), Err(RangeError::InvalidToken(_))));
+    assert!(matches!(Range::parse("AKs-QJo"), Err(RangeError::InvalidToken(_))));
+}
+
+#[test]
+fn card_removal_against_board() {
+    let range = Range::parse("AA").unwrap();
+    let board = [Card::new(Rank::Ace, Suit::Spades)];
+    assert_eq!(range.without(&board).combos.len(), 3);
+}
+
+#[test]
+fn range_against_range_on_river() {
+    let board = [
+        Card::new(Rank::Two, Suit::Clubs),
+        Card::new(Rank::Seven, Suit::Diamonds),
+        Card::new(Rank::Nine, Suit::Hearts),
+        Card::new(Rank::Jack, Suit::Spades),
+        Card::new(Rank::Four, Suit::Clubs),
+    ];
+    let hero = Range::parse("AA").unwrap();
+    let villain = Range::parse("KK").unwrap();
+    let report = range_equity(&hero, &villain, &board, &[], EquityOptions::default()).unwrap();
+    assert!(report.exhaustive);
+    assert_eq!(report.players[0].equity, 100.0);
+}
+
+#[test]
+fn range_equity_rejects_duplicate_and_missing_cards() {
+    let hero = Range::parse("AA").unwrap();
+    let villain = Range::parse("KK").unwrap();
+    let options = EquityOptions::default();
+
+    let two = Card::new(Rank::Two, Suit::Clubs);
+    assert_eq!(
+        range_equity(&hero, &villain, &[two], &[two], options),
+        Err(RangeError::DuplicateCard(two))
+    );
+
+    // 에이스 둘, 킹 둘, 남은 3장만 빼고 모두 죽은 카드: 보드 5장을 채울 수 없다
+    let keep = [
+        Card::new(Rank::Ace, Suit::Spades),
+        Card::new(Rank::Ace, Suit::Hearts),
+        Card::new(Rank::King, Suit::Spades),
+        Card::new(Rank::King, Suit::Hearts),
+        Card::new(Rank::Two, Suit::Clubs),
+        Card::new(Rank::Three, Suit::Clubs),
+        Card::new(Rank::Four, Suit::Clubs),
+    ];
+    let dead: Vec<Card> = Deck::new().cards.into_iter().filter(|c| !keep.contains(c)).collect();
+    assert_eq!(dead.len(), 45);
+    assert_eq!(
+        range_equity(&hero, &villain, &[], &dead, options),
+        Err(RangeError::NotEnoughCards)
+    );
+    let sampled = EquityOptions {
+        exhaustive_limit: 0,
+        ..options
+    };
+    assert_eq!(
+        range_equity(&hero, &villain, &[], &dead, sampled),
+        Err(RangeError::NotEnoughCards)
+    );
+}
+
+#[test]
+fn range_against_range_preflop_is_seeded() {
+    let hero = Range::parse("QQ+, AKs").unwrap();
+    let villain = Range::parse("22-55, 76s-54s").unwrap();
+    let options = EquityOptions {
+        exhaustive_limit: 0,
+        samples: 20_000,
+        seed: 3,
+    };
+    let first = range_equity(&hero, &villain, &[], &[], options).unwrap();
+    let second = range_equity(&hero, &villain, &[], &[], options).unwrap();
+    assert_eq!(first, second);
+    assert!(first.players[0].equity > 60.0);
+}
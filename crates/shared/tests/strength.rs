@@ -0,0 +1,128 @@
+use holdem_shared::combinatorics::next_combination;
+use holdem_shared::*;
+use rand::{SeedableRng, seq::SliceRandom};
+use rand_chacha::ChaCha20Rng;
+
+fn all_cards() -> Vec<Card> {
+    Deck::new().cards
+}
+
+/// 모든 5장 조합(2,598,960개)에서 `evaluate_hand`와 같은 결과인지 확인
+#[test]
+fn matches_evaluate_hand_for_every_five_card_hand() {
+    let cards = all_cards();
+    let mut checked = 0;
+    for a in 0..52 {
+        for b in a + 1..52 {
+            for c in b + 1..52 {
+                for d in c + 1..52 {
+                    for e in d + 1..52 {
+                        let hand = [cards[a], cards[b], cards[c], cards[d], cards[e]];
+                        let expected = HandStrength::from_value(&evaluate_hand(&hand).unwrap());
+                        assert_eq!(evaluate_strength(&hand), Ok(expected), "{:?}", hand);
+                        checked += 1;
+                    }
+                }
+            }
+        }
+    }
+    assert_eq!(checked, 2_598_960);
+}
+
+/// 6, 7장은 시드로 고정한 무작위 표본에서 `find_best_hand`와 비교한다.
+/// 7장 조합은 133,784,560개라 `find_best_hand`(조합당 5장 평가 21번)로 전부 비교하면
+/// 디버그 빌드 테스트로는 너무 오래 걸린다. 기본 테스트는 아래처럼 작은 덱에서 전수 검사하고,
+/// 52장 전수 검사는 `#[ignore]` 테스트로 따로 돌린다.
+#[test]
+fn matches_find_best_hand_for_six_and_seven_cards() {
+    let mut rng = ChaCha20Rng::seed_from_u64(2024);
+    let mut cards = all_cards();
+    for n in [6, 7] {
+        for _ in 0..50_000 {
+            cards.shuffle(&mut rng);
+            let hand = &cards[..n];
+            let (_, value) = find_best_hand(hand).unwrap();
+            assert_eq!(evaluate_strength(hand), Ok(HandStrength::from_value(&value)), "{:?}", hand);
+        }
+    }
+}
+
+/// `cards`에서 뽑을 수 있는 모든 6장, 7장 조합이 5장 부분 집합 세기의 최댓값과 같은지 확인하고
+/// 확인한 조합 수를 돌려준다. 5장 세기는 위에서 `evaluate_hand`와 전부 맞춰 보았으므로
+/// `find_best_hand`와 같은 기준이다.
+fn check_every_six_and_seven_card_hand(cards: &[Card]) -> [u64; 2] {
+    let mut counts = [0; 2];
+    for n in [6, 7] {
+        let mut idx: Vec<usize> = (0..n).collect();
+        let mut buffer = [cards[0]; 7];
+        loop {
+            let hand = &mut buffer[..n];
+            for (slot, &i) in hand.iter_mut().zip(&idx) {
+                *slot = cards[i];
+            }
+            let mut five = [0, 1, 2, 3, 4];
+            let mut best = None;
+            loop {
+                let subset = five.map(|i| hand[i]);
+                best = best.max(evaluate_strength(&subset).ok());
+                if !next_combination(&mut five, n) {
+                    break;
+                }
+            }
+            assert_eq!(evaluate_strength(hand).ok(), best, "{:?}", hand);
+            counts[n - 6] += 1;
+            if !next_combination(&mut idx, cards.len()) {
+                break;
+            }
+        }
+    }
+    counts
+}
+
+/// A~6 네 무늬(24장)로 만들 수 있는 6장, 7장 조합 전부. 스트레이트 플러시(휠 포함)부터
+/// 하이 카드까지 모든 족보와 에이스 로우가 나오면서 디버그 빌드에서도 금방 끝난다.
+#[test]
+fn matches_best_five_for_every_hand_from_ace_to_six() {
+    let low_ranks = [Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six];
+    let cards: Vec<Card> = all_cards().into_iter().filter(|c| low_ranks.contains(&c.rank)).collect();
+    assert_eq!(cards.len(), 24);
+    assert_eq!(check_every_six_and_seven_card_hand(&cards), [134_596, 346_104]);
+}
+
+/// 52장 전체의 6장, 7장 조합 전부.
+/// 릴리스 빌드에서 `cargo test --release -p holdem-shared --test strength -- --ignored`로 돌린다.
+#[test]
+#[ignore = "전수 검사라 --release에서도 몇 분 걸림"]
+fn matches_best_five_for_every_six_and_seven_card_hand() {
+    assert_eq!(
+        check_every_six_and_seven_card_hand(&all_cards()),
+        [20_358_520, 133_784_560]
+    );
+}
+
+#[test]
+fn wrong_card_counts_and_duplicates_are_errors() {
+    let cards = all_cards();
+    assert_eq!(
+        evaluate_strength(&cards[..4]),
+        Err(HandError::WrongCardCount { expected: 5, got: 4 })
+    );
+    let hand = [cards[0], cards[1], cards[2], cards[3], cards[0]];
+    assert_eq!(evaluate_strength(&hand), Err(HandError::DuplicateCard(cards[0])));
+}
+
+/// 세기의 대소 관계가 `HandValue`의 대소 관계와 같은지 확인
+#[test]
+fn ordering_matches_hand_value() {
+    let mut rng = ChaCha20Rng::seed_from_u64(7);
+    let mut cards = all_cards();
+    for _ in 0..50_000 {
+        cards.shuffle(&mut rng);
+        let (_, a) = find_best_hand(&cards[..7]).unwrap();
+        let (_, b) = find_best_hand(&cards[7..14]).unwrap();
+        let strength_a = evaluate_strength(&cards[..7]).unwrap();
+        let strength_b = evaluate_strength(&cards[7..14]).unwrap();
+        assert_eq!(a.cmp(&b), strength_a.cmp(&strength_b));
+        assert_eq!(strength_a.rank(), a.rank);
+    }
+}
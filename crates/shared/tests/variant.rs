@@ -0,0 +1,307 @@
+mod common;
+
+use common::{cards, seat, stacked_deck};
+use holdem_shared::*;
+
+#[test]
+fn omaha_must_use_exactly_two_hole_cards() {
+    // 홀덤이면 로얄 플러시지만 오마하는 스페이드가 홀카드에 한 장뿐이다
+    let hole = cards("Ts9c4d5c");
+    let board = cards("AsKsQsJs2h");
+    let (_, holdem) = Variant::Holdem.best_hand(&hole[..2], &board).unwrap();
+    assert_eq!(holdem.rank, HandRank::RoyalFlush);
+
+    let (best, omaha) = best_omaha_hand(&hole, &board).unwrap();
+    assert_eq!(omaha.rank, HandRank::Straight);
+    assert_eq!(best.iter().filter(|c| hole.contains(c)).count(), 2);
+
+    // 보드의 포카드도 홀카드 2장을 써야 하므로 그대로 쓸 수 없다
+    let (_, value) = best_omaha_hand(&cards("2c3d8h9h"), &cards("AhAdAcAsKd")).unwrap();
+    assert_eq!(value.rank, HandRank::ThreeOfAKind);
+}
+
+#[test]
+fn omaha_rejects_bad_input() {
+    assert_eq!(
+        best_omaha_hand(&cards("Ah"), &cards("2c3d4h5s6s")),
+        Err(HandError::WrongCardCount { expected: 2, got: 1 })
+    );
+    assert_eq!(
+        best_omaha_hand(&cards("AhKd"), &cards("2c3d")),
+        Err(HandError::WrongCardCount { expected: 3, got: 2 })
+    );
+    let ace = "Ah".parse().unwrap();
+    assert_eq!(
+        best_omaha_hand(&cards("AhKdQcJs"), &cards("Ah3d4h5s6s")),
+        Err(HandError::DuplicateCard(ace))
+    );
+}
+
+#[test]
+fn low_hands_need_five_distinct_ranks_eight_or_better() {
+    let wheel = LowHand::from_cards(&cards("Ah2d3c4s5h")).unwrap();
+    assert_eq!(wheel.values, vec![5, 4, 3, 2, 1]);
+    assert_eq!(wheel.to_string(), "5-4-3-2-A 로우");
+
+    let eight = LowHand::from_cards(&cards("8h7d3c2sAh")).unwrap();
+    let seven = LowHand::from_cards(&cards("7h6d5c4s2h")).unwrap();
+    assert!(seven.beats(&eight));
+    assert!(wheel.beats(&seven));
+
+    assert_eq!(LowHand::from_cards(&cards("9h7d3c2sAh")), None);
+    assert_eq!(LowHand::from_cards(&cards("7h7d3c2sAh")), None);
+}
+
+#[test]
+fn omaha_low_uses_two_hole_and_three_board() {
+    // 로우 카드가 보드에 둘뿐이면 로우가 없다
+    assert_eq!(best_omaha_low(&cards("Ah2d3c4s"), &cards("5h6dKcQsJh")), None);
+
+    let low = best_omaha_low(&cards("Ah2dKcKs"), &cards("3h6d8cQsJh")).unwrap();
+    assert_eq!(low.values, vec![8, 6, 3, 2, 1]);
+
+    // 홀덤은 로우를 나누지 않는다
+    assert_eq!(Variant::Holdem.best_low(&cards("Ah2d"), &cards("3h4d5cQsJh")), None);
+    assert!(Variant::OmahaHiLo.best_low(&cards("Ah2dKcKs"), &cards("3h4d5cQsJh")).is_some());
+}
+
+#[test]
+fn hi_lo_table_splits_pot_between_high_and_low() {
+    let mut table = Table::with_variant(Variant::OmahaHiLo);
+    table.seat_player(Player::new("p0".into(), "하이".into(), 1000)).unwrap();
+    table.seat_player(Player::new("p1".into(), "로우".into(), 1000)).unwrap();
+
+    // 홀카드는 p1, p0 순서로 한 장씩 네 바퀴, 이후 번 + 플랍, 번 + 턴, 번 + 리버
+    let p0 = cards("KhKc9s9h");
+    let p1 = cards("4s5sQcQh");
+    let mut draws = Vec::new();
+    for i in 0..4 {
+        draws.push(p1[i]);
+        draws.push(p0[i]);
+    }
+    draws.extend(cards("7cAh2d3c 8dKd 8hKs"));
+
+    let events = table.start_hand(stacked_deck(&draws)).unwrap();
+    assert!(events.contains(&TableEvent::HoleCardsDealt {
+        player_id: "p0".into(),
+        cards: p0.clone(),
+    }));
+
+    // 둘이서는 버튼(p0)이 스몰 블라인드로 프리플랍에 먼저, 플랍부터는 나중에 액션한다
+    table.apply("p0", Action::Call).unwrap();
+    table.apply("p1", Action::Check).unwrap();
+    let mut last = Vec::new();
+    for _ in 0..3 {
+        table.apply("p1", Action::Check).unwrap();
+        last = table.apply("p0", Action::Check).unwrap();
+    }
+
+    assert_eq!(table.state().community_cards, cards("Ah2d3cKdKs"));
+    let Some(TableEvent::HandEnded { winners, showdown }) = last.last() else {
+        panic!("핸드가 끝나야 합니다: {:?}", last);
+    };
+    assert_eq!(
+        winners,
+        &vec![
+            Payout { player_id: "p0".into(), amount: 10 },
+            Payout { player_id: "p1".into(), amount: 10 },
+        ]
+    );
+
+    let high = showdown.iter().find(|h| h.player_id == "p0").unwrap();
+    assert_eq!(high.value.rank, HandRank::FourOfAKind);
+    assert_eq!(high.low, None);
+    let low = showdown.iter().find(|h| h.player_id == "p1").unwrap();
+    assert_eq!(low.low.as_ref().unwrap().values, vec![5, 4, 3, 2, 1]);
+}
+
+fn one_to_ten(text: &str) -> HandValue {
+    let cards = cards(text);
+    best_one_to_ten_hand(&cards[..1], &cards[1..]).unwrap().1
+}
+
+#[test]
+fn one_to_ten_deck_has_forty_cards() {
+    let deck = Variant::OneToTen.deck_from(Deck::new());
+    assert_eq!(deck.cards.len(), 40);
+    assert!(deck.cards.iter().all(|c| c.rank <= Rank::Ten || c.rank == Rank::Ace));
+    assert_eq!(Variant::Holdem.deck_from(Deck::new()).cards.len(), 52);
+}
+
+#[test]
+fn one_to_ten_hands() {
+    assert_eq!(one_to_ten("7h7d7c").rank, HandRank::ThreeOfAKind);
+    assert_eq!(one_to_ten("Ah2d3c").rank, HandRank::Straight);
+    assert_eq!(one_to_ten("Ah2d3c").values, vec![3]);
+    assert_eq!(one_to_ten("8h9dTc").values, vec![10]);
+    // 에이스는 1이라 9-10-A는 스트레이트가 아니다
+    assert_eq!(one_to_ten("9hTdAc").rank, HandRank::HighCard);
+    assert_eq!(one_to_ten("9hTdAc").values, vec![10, 9, 1]);
+    assert_eq!(one_to_ten("5h5d9c").values, vec![5, 9]);
+    assert_eq!(
+        best_one_to_ten_hand(&cards("Ah"), &cards("2d3c4s")),
+        Err(HandError::WrongCardCount { expected: 3, got: 4 })
+    );
+
+    // 트리플 > 스트레이트 > 더블 > 하이
+    let variant = Variant::OneToTen;
+    let ordered = ["2h4d9c", "Ah9dTc", "AhAd2c", "2h2d3c", "Ah2d3c", "8h9dTc", "AhAdAc", "ThTdTc"];
+    for pair in ordered.windows(2) {
+        let (low, high) = (one_to_ten(pair[0]), one_to_ten(pair[1]));
+        assert_eq!(variant.compare_hands(&high, &low), std::cmp::Ordering::Greater, "{:?}", pair);
+    }
+    // 홀덤 순서에서는 스트레이트가 트리플보다 높다
+    let (straight, triple) = (one_to_ten("Ah2d3c"), one_to_ten("2h2d2c"));
+    assert_eq!(Variant::Holdem.compare_hands(&straight, &triple), std::cmp::Ordering::Greater);
+    assert_eq!(variant.compare_hands(&straight, &triple), std::cmp::Ordering::Less);
+}
+
+/// 하우스 게임 서열 (docs/README.md 5절): 트리플 > 스트레이트 > 더블 > 하이 카드
+#[test]
+fn one_to_ten_category_order_is_pinned() {
+    let variant = Variant::OneToTen;
+    let mut ranks = vec![
+        HandRank::ThreeOfAKind,
+        HandRank::HighCard,
+        HandRank::Straight,
+        HandRank::OnePair,
+    ];
+    ranks.sort_by_key(|&rank| variant.category_order(rank));
+    assert_eq!(
+        ranks,
+        vec![HandRank::HighCard, HandRank::OnePair, HandRank::Straight, HandRank::ThreeOfAKind]
+    );
+    // 다른 게임은 홀덤 서열 그대로다
+    let holdem = |rank| Variant::Holdem.category_order(rank);
+    assert!(holdem(HandRank::Straight) > holdem(HandRank::ThreeOfAKind));
+}
+
+#[test]
+fn one_to_ten_table_plays_two_betting_rounds() {
+    let mut table = Table::with_variant(Variant::OneToTen);
+    seat(&mut table, &[1000; 4]);
+    assert_eq!(
+        table.seat_player(Player::new("p4".into(), "다섯째".into(), 1000)),
+        Err(TableError::TableFull { max_players: 4 })
+    );
+
+    // 홀카드 p1, p2, p3, p0 한 장씩, 데스크 1장 (번 카드 없음)
+    table.start_hand(stacked_deck(&cards("7h5d9cAh8s7d"))).unwrap();
+    assert_eq!(table.state().community_cards, cards("8s"));
+    assert_eq!(table.state().players[0].hand, cards("Ah"));
+    assert_eq!(table.state().phase, GamePhase::PreFlop);
+
+    for id in ["p3", "p0", "p1"] {
+        table.apply(id, Action::Call).unwrap();
+    }
+    table.apply("p2", Action::Check).unwrap();
+    assert_eq!(table.state().community_cards, cards("8s7d"));
+    assert_eq!(table.state().phase, GamePhase::Flop);
+    assert_eq!(
+        table.set_variant(Variant::Holdem),
+        Err(TableError::HandInProgress)
+    );
+
+    let mut events = Vec::new();
+    for id in ["p1", "p2", "p3", "p0"] {
+        events = table.apply(id, Action::Check).unwrap();
+    }
+
+    // p3의 9-8-7 스트레이트가 p1의 7 더블을 이긴다
+    let Some(TableEvent::HandEnded { winners, showdown }) = events.last() else {
+        panic!("핸드가 끝나야 합니다: {:?}", events);
+    };
+    assert_eq!(winners, &vec![Payout { player_id: "p3".into(), amount: 40 }]);
+    let double = showdown.iter().find(|h| h.player_id == "p1").unwrap();
+    assert_eq!(double.value.rank, HandRank::OnePair);
+    assert!(table.deal_log().iter().all(|d| d.target != DealTarget::Burn));
+
+    // 핸드가 끝나면 게임 종류를 바꿀 수 있지만 인원 제한을 넘으면 안 된다
+    assert_eq!(table.set_variant(Variant::Omaha), Ok(()));
+    table.seat_player(Player::new("p4".into(), "다섯째".into(), 1000)).unwrap();
+    assert_eq!(
+        table.set_variant(Variant::OneToTen),
+        Err(TableError::TableFull { max_players: 4 })
+    );
+}
+
+#[test]
+fn players_seated_mid_hand_wait_for_the_next_hand() {
+    for variant in Variant::ALL {
+        let mut table = Table::with_variant(variant);
+        seat(&mut table, &[1000, 1000]);
+        table.start_hand(Deck::new()).unwrap();
+        table.seat_player(Player::new("late".into(), "늦게 온".into(), 1000)).unwrap();
+        let late = &table.state().players[2];
+        assert!(late.folded && late.hand.is_empty(), "{variant}");
+
+        // 끝까지 콜/체크. 늦게 온 플레이어에게는 차례가 오지 않는다
+        let mut events = Vec::new();
+        while table.state().is_betting() {
+            let state = table.state();
+            let player = &state.players[state.current_player_idx];
+            assert_ne!(player.id, "late", "{variant}");
+            let action = if player.bet < state.current_bet { Action::Call } else { Action::Check };
+            let id = player.id.clone();
+            events = table.apply(&id, action).unwrap();
+        }
+        let Some(TableEvent::HandEnded { winners, showdown }) = events.last() else {
+            panic!("{variant}: 핸드가 끝나야 합니다: {:?}", events);
+        };
+        assert!(winners.iter().all(|w| w.player_id != "late"), "{variant}");
+        assert!(showdown.iter().all(|h| h.player_id != "late"), "{variant}");
+
+        // 다음 핸드부터는 카드를 받는다
+        table.start_hand(Deck::new()).unwrap();
+        let late = &table.state().players[2];
+        assert!(!late.folded, "{variant}");
+        assert_eq!(late.hand.len(), variant.hole_cards(), "{variant}");
+    }
+}
+
+#[test]
+fn decks_are_built_from_specs() {
+    assert_eq!(Deck::from_spec(&DeckSpec::standard()).cards, Deck::new().cards);
+    assert_eq!(DeckSpec::short().len(), 36);
+    assert_eq!(DeckSpec::one_to_ten().len(), 40);
+
+    let short = Deck::from_spec(&Variant::ShortDeck.deck_spec());
+    assert_eq!(short.cards.len(), 36);
+    assert!(short.cards.iter().all(|c| c.rank >= Rank::Six));
+    // 52장 셔플에서 뺀 덱도 같은 카드 집합이다
+    let mut filtered = Variant::ShortDeck.deck_from(Deck::new()).cards;
+    filtered.sort_by_key(|c| c.index());
+    let mut built = short.cards;
+    built.sort_by_key(|c| c.index());
+    assert_eq!(filtered, built);
+}
+
+#[test]
+fn short_deck_low_straight_and_flush_over_full_house() {
+    let low = evaluate_short_deck_hand(&cards("Ah6d7c8s9h")).unwrap();
+    assert_eq!(low.rank, HandRank::Straight);
+    assert_eq!(low.values, vec![9]);
+    let six_high = evaluate_short_deck_hand(&cards("Th6d7c8s9h")).unwrap();
+    assert_eq!(Variant::ShortDeck.compare_hands(&six_high, &low), std::cmp::Ordering::Greater);
+
+    let steel = evaluate_short_deck_hand(&cards("Ah6h7h8h9h")).unwrap();
+    assert_eq!(steel.rank, HandRank::StraightFlush);
+    // 홀덤 평가로는 그냥 플러시
+    assert_eq!(evaluate_hand(&cards("Ah6h7h8h9h")).unwrap().rank, HandRank::Flush);
+
+    let flush = evaluate_short_deck_hand(&cards("AhJh9h7h6h")).unwrap();
+    let full_house = evaluate_short_deck_hand(&cards("AhAdAcKsKh")).unwrap();
+    assert_eq!(Variant::ShortDeck.compare_hands(&flush, &full_house), std::cmp::Ordering::Greater);
+    assert_eq!(Variant::Holdem.compare_hands(&flush, &full_house), std::cmp::Ordering::Less);
+
+    // 7장에서도 A-6-7-8-9를 찾는다
+    let (best, value) = Variant::ShortDeck
+        .best_hand(&cards("AhKd"), &cards("6c7s8d9cQh"))
+        .unwrap();
+    assert_eq!(value.rank, HandRank::Straight);
+    assert_eq!(best.len(), 5);
+    let (_, holdem) = Variant::Holdem
+        .best_hand(&cards("AhKd"), &cards("6c7s8d9cQh"))
+        .unwrap();
+    assert_eq!(holdem.rank, HandRank::HighCard);
+}